@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+use crate::{Error, Result};
+
+/// Expected answers keyed by day and input name, read from a small subset of TOML:
+///
+/// ```toml
+/// # Answers for inputs/day05.txt
+/// [day05.day05]
+/// part1 = 35
+/// part2 = "46"
+/// ```
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    expected: HashMap<(u8, String), [Option<String>; 2]>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::new(format!("could not read {}: {e}", path.display())))?;
+        content.parse().map_err(|e: Error| Error::new(format!("{}: {e}", path.display())))
+    }
+
+    pub fn expected(&self, day: u8, input: &str, part: u8) -> Option<&str> {
+        self.expected.get(&(day, input.to_owned()))?
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+
+    pub fn verify(&self, day: u8, input: &str, part: u8, answer: &str) -> Verdict {
+        match self.expected(day, input, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_owned() },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut section = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let result = if let Some(header) = line.strip_prefix('[') {
+                parse_header(header).map(|key| {
+                    answers.expected.entry(key.clone()).or_default();
+                    section = Some(key);
+                })
+            } else {
+                let Some(key) = &section else {
+                    return Err(Error::new("expected a [dayNN.<input>] section").with_line(i + 1));
+                };
+                parse_entry(line).map(|(part, answer)| answers.expected.get_mut(key).unwrap()[part] = Some(answer))
+            };
+
+            result.map_err(|e| e.with_line(i + 1))?;
+        }
+
+        Ok(answers)
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+fn parse_header(header: &str) -> Result<(u8, String)> {
+    let header = header.strip_suffix(']').ok_or_else(|| Error::new("expected ']'"))?;
+    let (day, input) = header.split_once('.')
+        .ok_or_else(|| Error::new(format!("expected [dayNN.<input>], found [{header}]")))?;
+    let day = day.trim().strip_prefix("day")
+        .and_then(|d| d.parse().ok())
+        .ok_or_else(|| Error::new(format!("invalid day '{}'", day.trim())))?;
+
+    Ok((day, unquote(input.trim())?))
+}
+
+fn parse_entry(line: &str) -> Result<(usize, String)> {
+    let (key, value) = line.split_once('=').ok_or_else(|| Error::new("expected '<key> = <value>'"))?;
+    let part = match key.trim() {
+        "part1" => 0,
+        "part2" => 1,
+        key => return Err(Error::new(format!("unknown key '{key}', expected part1 or part2"))),
+    };
+
+    let value = value.trim();
+    let answer = if value.starts_with('"') {
+        unquote(value)?
+    } else if value.parse::<i128>().is_ok() {
+        value.trim_start_matches('+').to_owned()
+    } else {
+        return Err(Error::new(format!("expected a string or integer, found '{value}'")));
+    };
+
+    Ok((part, answer))
+}
+
+/// Removes the quotes and escapes of a basic TOML string, returning bare keys unchanged.
+fn unquote(s: &str) -> Result<String> {
+    let Some(inner) = s.strip_prefix('"') else {
+        return Ok(s.to_owned());
+    };
+    let inner = inner.strip_suffix('"').ok_or_else(|| Error::new("unterminated string"))?;
+
+    let mut result = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        result.push(match c {
+            '\\' => match chars.next() {
+                Some('"') => '"',
+                Some('\\') => '\\',
+                Some('n') => '\n',
+                Some('t') => '\t',
+                c => return Err(Error::new(format!("unsupported escape '\\{}'", c.unwrap_or(' ')))),
+            },
+            c => c,
+        });
+    }
+
+    Ok(result)
+}
+
+/// The outcome of comparing an answer with the expected one.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+    use super::{Answers, Verdict};
+
+    const ANSWERS: &str = r#"
+# Expected answers
+[day05.example]
+part1 = 35
+part2 = "46" # trailing comment
+
+[day7."alice #2"]
+part1 = "6440"
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers = ANSWERS.parse::<Answers>().unwrap();
+
+        assert_eq!(answers.expected(5, "example", 1), Some("35"));
+        assert_eq!(answers.expected(5, "example", 2), Some("46"));
+        assert_eq!(answers.expected(7, "alice #2", 1), Some("6440"));
+        assert_eq!(answers.expected(7, "alice #2", 2), None);
+        assert_eq!(answers.expected(7, "bob", 1), None);
+        assert_eq!(answers.expected(5, "example", 3), None);
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = ANSWERS.parse::<Answers>().unwrap();
+
+        assert_eq!(answers.verify(5, "example", 1, "35"), Verdict::Pass);
+        assert_eq!(answers.verify(5, "example", 2, "47"), Verdict::Fail { expected: "46".to_owned() });
+        assert_eq!(answers.verify(7, "alice #2", 2, "5905"), Verdict::Missing);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("part1 = 1".parse::<Answers>(), Err(Error::new("expected a [dayNN.<input>] section").with_line(1)));
+        assert_eq!("[day5]".parse::<Answers>(), Err(Error::new("expected [dayNN.<input>], found [day5]").with_line(1)));
+        assert_eq!("[five.x]".parse::<Answers>(), Err(Error::new("invalid day 'five'").with_line(1)));
+        assert_eq!("[day5.x]\npart3 = 1".parse::<Answers>(), Err(Error::new("unknown key 'part3', expected part1 or part2").with_line(2)));
+        assert_eq!("[day5.x]\npart1 = abc".parse::<Answers>(), Err(Error::new("expected a string or integer, found 'abc'").with_line(2)));
+    }
+}
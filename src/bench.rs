@@ -0,0 +1,194 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use crate::{DynSolution, Error, Result, Run};
+
+/// Environment variable enabling bench mode, optionally holding a [`Limit`].
+pub const BENCH_ENV: &str = "AOC_BENCH";
+
+/// How long a benchmark keeps sampling after warming up.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Limit {
+    Iterations(usize),
+    Budget(Duration),
+}
+
+impl Default for Limit {
+    fn default() -> Self { Limit::Budget(Duration::from_secs(1)) }
+}
+
+impl FromStr for Limit {
+    type Err = Error;
+
+    /// Parses either an iteration count (`100`) or a time budget (`2s`, `500ms`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::new(format!("invalid bench limit '{s}', expected e.g. 100, 2s or 500ms"));
+
+        let limit = if let Some(ms) = s.strip_suffix("ms") {
+            Limit::Budget(Duration::from_millis(ms.parse().map_err(|_| invalid())?))
+        } else if let Some(secs) = s.strip_suffix('s') {
+            Limit::Budget(Duration::try_from_secs_f64(secs.parse().map_err(|_| invalid())?).map_err(|_| invalid())?)
+        } else {
+            Limit::Iterations(s.parse().map_err(|_| invalid())?)
+        };
+
+        match limit {
+            Limit::Iterations(0) => Err(invalid()),
+            limit => Ok(limit),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub limit: Limit,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self { BenchConfig { warmup: 3, limit: Limit::default() } }
+}
+
+impl BenchConfig {
+    /// Reads the configuration from [`BENCH_ENV`], returning `None` when it is unset.
+    pub fn from_env() -> Result<Option<Self>> {
+        match std::env::var(BENCH_ENV) {
+            Ok(limit) if limit.is_empty() => Ok(Some(BenchConfig::default())),
+            Ok(limit) => Ok(Some(BenchConfig { limit: limit.parse()?, ..BenchConfig::default() })),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+/// Summary statistics over the samples of a single phase.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics of `samples`, which must not be empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        samples.sort_unstable();
+        let n = samples.len();
+
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples.iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>() / n as f64;
+
+        Stats {
+            samples: n,
+            min: samples[0],
+            median,
+            mean,
+            p95: samples[(n * 95).div_ceil(100) - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// The answers of a day together with statistics over repeated runs of each phase.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub day: u8,
+    pub part1: String,
+    pub part2: String,
+    pub prepare_stats: Stats,
+    pub part1_stats: Stats,
+    pub part2_stats: Stats,
+}
+
+impl Display for Bench {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Input preparation runtime: {}", format_stats(&self.prepare_stats))?;
+        writeln!(f, "Part 1: {} ({})", self.part1, format_stats(&self.part1_stats))?;
+        writeln!(f, "Part 2: {} ({})", self.part2, format_stats(&self.part2_stats))?;
+        write!(f, "Samples: {}", self.prepare_stats.samples)
+    }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!("min {:?}, median {:?}, mean {:?}, p95 {:?}, stddev {:?}",
+            stats.min, stats.median, stats.mean, stats.p95, stats.stddev)
+}
+
+/// Runs `solution` repeatedly on `input`, discarding the warm-up runs.
+pub fn bench(solution: &dyn DynSolution, input: &str, config: &BenchConfig) -> Result<Bench> {
+    for _ in 0..config.warmup {
+        solution.run(input)?;
+    }
+
+    let mut runs = vec![];
+    let start = Instant::now();
+    loop {
+        runs.push(solution.run(input)?);
+
+        let done = match config.limit {
+            Limit::Iterations(n) => runs.len() >= n,
+            Limit::Budget(budget) => start.elapsed() >= budget,
+        };
+        if done {
+            break;
+        }
+    }
+
+    let stats = |f: fn(&Run) -> Duration| Stats::from_samples(&mut runs.iter().map(f).collect::<Vec<_>>());
+    let last = runs.last().unwrap();
+
+    Ok(Bench {
+        day: last.day,
+        part1: last.part1.clone(),
+        part2: last.part2.clone(),
+        prepare_stats: stats(|r| r.prepare_duration),
+        part1_stats: stats(|r| r.part1_duration),
+        part2_stats: stats(|r| r.part2_duration),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::{Limit, Stats};
+
+    #[test]
+    fn test_parse_limit() {
+        assert_eq!("100".parse(), Ok(Limit::Iterations(100)));
+        assert_eq!("2s".parse(), Ok(Limit::Budget(Duration::from_secs(2))));
+        assert_eq!("0.5s".parse(), Ok(Limit::Budget(Duration::from_millis(500))));
+        assert_eq!("250ms".parse(), Ok(Limit::Budget(Duration::from_millis(250))));
+        assert!("0".parse::<Limit>().is_err());
+        assert!("fast".parse::<Limit>().is_err());
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut samples = (1..=20).rev().map(Duration::from_millis).collect::<Vec<_>>();
+        let stats = Stats::from_samples(&mut samples);
+
+        assert_eq!(stats.samples, 20);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.stddev.as_micros(), 5766);
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(&mut [Duration::from_millis(3)]);
+        assert_eq!((stats.min, stats.median, stats.p95), (Duration::from_millis(3), Duration::from_millis(3), Duration::from_millis(3)));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,664 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use aoc2023::{days, DynSolution, Error, Result};
+use aoc2023::answers::{Answers, Verdict};
+use aoc2023::bench::{bench, BenchConfig};
+use aoc2023::days::day5::diagnose;
+use aoc2023::days::day10::{Map, Style};
+use aoc2023::days::day7::{compare_explained, parse_hands, ranking, RuleSet, solver};
+use aoc2023::history::{Entry, find_regressions, History, HISTORY_FILE};
+use aoc2023::input::InputSource;
+use aoc2023::output::{CSV_HEADER, Format, Record, table};
+
+const USAGE: &str = "Usage: aoc run <DAYS> [INPUT] [OPTIONS]
+       aoc maze [INPUT] [--ascii] [--svg=FILE]
+       aoc camel [INPUT] [--rules=NAME] [--ranking]
+       aoc almanac [INPUT]
+       aoc list
+
+DAYS is a single day (5), a range (1..=10 or 1..11) or `all`.
+INPUT is the path of the input file, or - to read stdin, and can only be given for a single
+day. Without it each day reads inputs/dayNN.txt. A directory or a glob such as
+inputs/day05/*.txt runs the day on every matching file and prints a table of the results.
+
+--jobs[=N]       Run up to N days in parallel, one per available core if N is omitted,
+                 which is the default when several days are selected. Each day's output
+                 is still printed as a whole and in order. Days always run one at a time
+                 with --bench or --history.
+--bench[=LIMIT]  Repeat each day and report statistics per phase. LIMIT is an iteration
+                 count (100) or a time budget (2s, 500ms) and defaults to 1s. Bench mode
+                 can also be enabled through the AOC_BENCH environment variable.
+--format=FORMAT  Report answers and timings as text on stderr (default), or as json lines
+                 or csv records on stdout.
+--verify[=FILE]  Compare the answers with those in FILE (answers.toml by default) and fail
+                 on any mismatch. Answers are keyed by day and input name, which is the
+                 file name without extension or `stdin`.
+--history[=FILE] Append the timings of every phase to FILE (history.csv by default) and
+                 flag phases that got slower than in the previous run.
+--threshold=PCT  Percentage a phase may get slower before it is flagged, 10 by default.
+--baseline       Compare with the last baseline run instead of the previous run.
+--set-baseline   Mark this run as the new baseline in the history.
+
+`maze` draws the day 10 pipe maze with box-drawing characters (or the input's own with
+--ascii), marking tiles inside the main loop I and outside it O. --svg writes an SVG image
+to FILE instead.
+
+`camel` prints the day 7 total winnings under the rule set NAME: standard (default), jokers,
+aces-low, deuces-and-jokers, jokers-not-in-ties or poker. Cards may carry a suit (As, Th, 2c,
+9d) on every card of a hand, making flushes count. --ranking prints every hand from weakest to
+strongest with its type, the cards its wild cards stand in for, its winnings and why it beats
+the hand below it.
+
+`almanac` lists every overlapping, empty or overflowing range in the maps of a day 5 almanac
+and fails if there are any.";
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    selection: Vec<u8>,
+    input: Option<String>,
+    jobs: usize,
+    bench: Option<BenchConfig>,
+    format: Format,
+    verify: Option<PathBuf>,
+    history: Option<PathBuf>,
+    threshold: f64,
+    baseline: bool,
+    set_baseline: bool,
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        Some("run") => match parse_options(&args[1..]) {
+            Ok(options) => run(&options),
+            Err(e) => {
+                eprintln!("{e}\n\n{USAGE}");
+                ExitCode::FAILURE
+            }
+        },
+        Some("maze") => match draw_maze(&args[1..]) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Some("camel") => match play_camel_cards(&args[1..]) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Some("almanac") => match check_almanac(&args[1..]) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Some("list") if args.len() == 1 => {
+            days::SOLUTIONS.iter().for_each(|s| println!("{:>2}: {}", s.day(), s.title()));
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<Options> {
+    let mut selection = None;
+    let mut input = None;
+    let mut jobs = None;
+    let mut bench = None;
+    let mut format = Format::default();
+    let mut verify = None;
+    let mut history = None;
+    let mut threshold = 10.0;
+    let mut baseline = false;
+    let mut set_baseline = false;
+
+    for arg in args {
+        match arg.split_once('=').unwrap_or((arg, "")) {
+            ("--jobs", "") => jobs = Some(available_jobs()),
+            ("--jobs", n) => jobs = Some(n.parse().ok().filter(|n| *n > 0)
+                .ok_or_else(|| Error::new(format!("invalid number of jobs '{n}'")))?),
+            ("--bench", "") => bench = Some(BenchConfig::default()),
+            ("--bench", limit) => bench = Some(BenchConfig { limit: limit.parse()?, ..BenchConfig::default() }),
+            ("--format", f) => format = f.parse()?,
+            ("--verify", "") => verify = Some(PathBuf::from("answers.toml")),
+            ("--verify", path) => verify = Some(PathBuf::from(path)),
+            ("--history", "") => history = Some(PathBuf::from(HISTORY_FILE)),
+            ("--history", path) => history = Some(PathBuf::from(path)),
+            ("--threshold", t) => threshold = t.trim_end_matches('%').parse().ok().filter(|t: &f64| *t >= 0.0)
+                .ok_or_else(|| Error::new(format!("invalid threshold '{t}'")))?,
+            ("--baseline", "") => baseline = true,
+            ("--set-baseline", "") => set_baseline = true,
+            _ if arg.starts_with("--") => return Err(Error::new(format!("unknown option '{arg}'"))),
+            _ if selection.is_none() => selection = Some(parse_selection(arg)
+                .ok_or_else(|| Error::new(format!("invalid day selection '{arg}'")))?),
+            _ if input.is_none() => input = Some(arg.clone()),
+            _ => return Err(Error::new(format!("unexpected argument '{arg}'"))),
+        }
+    }
+
+    let selection = selection.ok_or_else(|| Error::new("missing day selection"))?;
+    if input.is_some() && selection.len() != 1 {
+        return Err(Error::new("an input can only be given for a single day"));
+    }
+
+    let bench = match bench {
+        Some(bench) => Some(bench),
+        None => BenchConfig::from_env()?,
+    };
+
+    // Days competing for the cores would skew timings that are meant to be compared.
+    let timed = bench.is_some() || history.is_some();
+    let jobs = match jobs {
+        Some(n) if timed && n > 1 => {
+            eprintln!("Ignoring --jobs, days run one at a time when benchmarking or recording history");
+            1
+        }
+        Some(n) => n,
+        None if timed || selection.len() == 1 => 1,
+        None => available_jobs(),
+    };
+
+    Ok(Options {
+        selection,
+        input,
+        jobs,
+        bench,
+        format,
+        verify,
+        history,
+        threshold,
+        baseline,
+        set_baseline,
+    })
+}
+
+fn available_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+fn run(options: &Options) -> ExitCode {
+    let answers = match options.verify.as_deref().map(Answers::load).transpose() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let missing = unimplemented(&options.selection);
+    match options.selection[..] {
+        [day] if !missing.is_empty() => {
+            eprintln!("Day {day} is not implemented");
+            return ExitCode::FAILURE;
+        }
+        _ if missing.len() == options.selection.len() => {
+            eprintln!("None of the selected days are implemented");
+            return ExitCode::FAILURE;
+        }
+        _ if !missing.is_empty() => eprintln!("Skipping days that are not implemented: {}",
+            missing.iter().map(u8::to_string).collect::<Vec<_>>().join(", ")),
+        _ => {}
+    }
+
+    if options.format == Format::Csv {
+        println!("{CSV_HEADER}");
+    }
+
+    let solutions = days::SOLUTIONS.iter()
+        .filter(|s| options.selection.contains(&s.day()))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    let mut records = vec![];
+    let mut passed = true;
+
+    run_pool(solutions.len(), options.jobs, |i| run_day(solutions[i], options, answers.as_ref()), |i, report| {
+        let report = report.unwrap_or_else(|message| Report {
+            stderr: vec![format!("Day {} panicked: {message}", solutions[i].day())],
+            stdout: vec![],
+            records: vec![],
+            passed: false,
+        });
+        report.stderr.iter().for_each(|l| eprintln!("{l}"));
+        report.stdout.iter().for_each(|l| println!("{l}"));
+        records.extend(report.records);
+        passed &= report.passed;
+    });
+
+    if options.format == Format::Text && solutions.len() > 1 {
+        let total = records.iter().map(|r| r.duration).sum::<Duration>()
+            + records.iter().filter(|r| r.part == 1).map(|r| r.prepare).sum::<Duration>();
+
+        eprintln!("Summary");
+        eprintln!("{}", table(&records));
+        eprintln!("Total runtime: {total:?} (wall clock: {:?})", start.elapsed());
+    }
+
+    if let Some(path) = &options.history {
+        if let Err(e) = record_history(path, &records, options) {
+            eprintln!("{e}");
+            passed = false;
+        }
+    }
+
+    if passed { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+/// Compares the timings with the history at `path` and appends them to it.
+fn record_history(path: &Path, records: &[Record], options: &Options) -> Result<()> {
+    if records.is_empty() {
+        return Ok(());
+    }
+
+    let history = History::load(path)?;
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_millis());
+    let entries = Entry::from_records(records, timestamp, options.set_baseline);
+
+    let reference = if options.baseline { "baseline" } else { "previous" };
+    let lookup = |e: &Entry| if options.baseline { history.baseline(e) } else { history.previous(e) };
+    let regressions = find_regressions(&entries, lookup, options.threshold);
+
+    if entries.iter().all(|e| lookup(e).is_none()) {
+        eprintln!("No {reference} run measured the same way was found in {}", path.display());
+    } else if regressions.is_empty() {
+        eprintln!("No phase got more than {}% slower than in the {reference} run", options.threshold);
+    } else {
+        eprintln!("Phases more than {}% slower than in the {reference} run:", options.threshold);
+        regressions.iter().for_each(|r| eprintln!("  {r}"));
+    }
+
+    History::append(path, &entries)
+}
+
+/// Everything a day reports, buffered so that days running in parallel do not interleave.
+struct Report {
+    stderr: Vec<String>,
+    stdout: Vec<String>,
+    records: Vec<Record>,
+    passed: bool,
+}
+
+/// Runs `work` for every index on up to `jobs` threads, handing the results to `done` in order.
+/// A panic in `work` is caught and handed on as its message, so the other indices still finish.
+/// The panic hook is silenced meanwhile, so the message is only printed where `done` puts it.
+fn run_pool<T: Send>(count: usize, jobs: usize, work: impl Fn(usize) -> T + Sync, mut done: impl FnMut(usize, Result<T, String>)) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, count.max(1)) {
+            let (next, work, sender) = (&next, &work, sender.clone());
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= count {
+                    break;
+                }
+                let result = panic::catch_unwind(AssertUnwindSafe(|| work(i))).map_err(|payload| panic_message(&*payload));
+                if sender.send((i, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut expected = 0;
+        for (i, result) in receiver {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&expected) {
+                done(expected, result);
+                expected += 1;
+            }
+        }
+    });
+
+    panic::set_hook(hook);
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "unknown panic".to_owned(),
+    }
+}
+
+fn run_day(solution: &dyn DynSolution, options: &Options, answers: Option<&Answers>) -> Report {
+    let mut report = Report { stderr: vec![], stdout: vec![], records: vec![], passed: true };
+    let text = options.format == Format::Text;
+    let several_days = options.selection.len() > 1;
+
+    if text && several_days {
+        report.stderr.push(format!("Day {}: {}", solution.day(), solution.title()));
+    }
+
+    let sources = match InputSource::expand(solution.day(), options.input.as_deref()) {
+        Ok(sources) => sources,
+        Err(e) => {
+            report.stderr.push(e.with_day(solution.day()).to_string());
+            report.passed = false;
+            return report;
+        }
+    };
+
+    // A single input is reported as it runs, several are summarised in a table.
+    let batch = sources.len() > 1;
+
+    for source in &sources {
+        match run_one(solution, source, options) {
+            Ok((text_report, records)) => {
+                if text && !batch {
+                    report.stderr.push(text_report);
+                }
+                report.records.extend(records);
+            }
+            Err(e) if batch => {
+                report.stderr.push(format!("{}: {e}", source.name()));
+                report.passed = false;
+            }
+            Err(e) => {
+                report.stderr.push(e.to_string());
+                report.passed = false;
+            }
+        }
+    }
+
+    match options.format {
+        Format::Text if batch => report.stderr.push(table(&report.records)),
+        Format::Text => {}
+        Format::Json => report.stdout.extend(report.records.iter().map(Record::to_json)),
+        Format::Csv => report.stdout.extend(report.records.iter().map(Record::to_csv)),
+    }
+
+    if let Some(answers) = answers {
+        for record in &report.records {
+            let verdict = answers.verify(record.day, &record.input, record.part, &record.answer);
+            report.stderr.push(format!("Verify day {} ({}) part {}: {verdict}", record.day, record.input, record.part));
+            report.passed &= !matches!(verdict, Verdict::Fail { .. });
+        }
+    }
+
+    if text && several_days {
+        report.stderr.push(String::new());
+    }
+
+    report
+}
+
+/// Runs a day on a single input, returning its text report and records.
+fn run_one(solution: &dyn DynSolution, source: &InputSource, options: &Options) -> Result<(String, [Record; 2])> {
+    let input = source.read().map_err(|e| e.with_day(solution.day()))?;
+
+    Ok(match &options.bench {
+        Some(config) => {
+            let bench = bench(solution, &input, config)?;
+            (bench.to_string(), Record::from_bench(&bench, &source.name()))
+        }
+        None => {
+            let run = solution.run(&input)?;
+            (run.to_string(), Record::from_run(&run, &source.name()))
+        }
+    })
+}
+
+/// Draws the day 10 maze as text on stdout or as an SVG file.
+fn draw_maze(args: &[String]) -> Result<()> {
+    let mut input = None;
+    let mut style = Style::Unicode;
+    let mut svg = None;
+
+    for arg in args {
+        match arg.split_once('=').unwrap_or((arg, "")) {
+            ("--ascii", "") => style = Style::Ascii,
+            ("--svg", "") => return Err(Error::new("--svg needs a file name")),
+            ("--svg", path) => svg = Some(PathBuf::from(path)),
+            _ if arg.starts_with("--") => return Err(Error::new(format!("unknown option '{arg}'"))),
+            _ if input.is_none() => input = Some(arg.as_str()),
+            _ => return Err(Error::new(format!("unexpected argument '{arg}'"))),
+        }
+    }
+
+    let map = InputSource::resolve(10, input).read()?.parse::<Map>().map_err(|e| e.with_day(10))?;
+    let main_loop = map.main_loop().map_err(|e| e.with_day(10))?;
+
+    match svg {
+        Some(path) => std::fs::write(&path, map.to_svg(&main_loop))
+            .map_err(|e| Error::new(format!("could not write {}: {e}", path.display()))),
+        None => {
+            println!("{}", map.render(&main_loop, style));
+            Ok(())
+        }
+    }
+}
+
+/// Prints the day 7 total winnings, or the full ranking, under one of the built-in rule sets.
+fn play_camel_cards(args: &[String]) -> Result<()> {
+    let mut input = None;
+    let mut rules = RuleSet::standard();
+    let mut show_ranking = false;
+
+    for arg in args {
+        match arg.split_once('=').unwrap_or((arg, "")) {
+            ("--rules", name) => rules = RuleSet::named(name)?,
+            ("--ranking", "") => show_ranking = true,
+            _ if arg.starts_with("--") => return Err(Error::new(format!("unknown option '{arg}'"))),
+            _ if input.is_none() => input = Some(arg.as_str()),
+            _ => return Err(Error::new(format!("unexpected argument '{arg}'"))),
+        }
+    }
+
+    let hands = parse_hands(&InputSource::resolve(7, input).read()?, &rules).map_err(|e| e.with_day(7))?;
+    if !show_ranking {
+        println!("{}", solver(&hands, &rules));
+        return Ok(());
+    }
+
+    let ranked = ranking(&hands, &rules);
+    let mut rows = vec![["Rank", "Hand", "Type", "Wild", "Bid", "Winnings", "Beats the previous hand on"].map(String::from)];
+    for (i, r) in ranked.iter().enumerate() {
+        let wild = r.substitution.iter().map(|(w, s)| format!("{}={}", char::from(*w), char::from(*s))).collect::<Vec<_>>();
+        rows.push([
+            r.rank.to_string(),
+            r.hand.to_string(),
+            format!("{:?}", r.hand_type),
+            if wild.is_empty() { "-".to_owned() } else { wild.join(" ") },
+            r.bid.to_string(),
+            r.winnings.to_string(),
+            i.checked_sub(1).map_or("-".to_owned(), |p| compare_explained(&r.hand, &ranked[p].hand, &rules).to_string()),
+        ]);
+    }
+
+    let widths = (0..rows[0].len()).map(|c| rows.iter().map(|r| r[c].len()).max().unwrap_or(0)).collect::<Vec<_>>();
+    for row in rows {
+        let cells = row.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).collect::<Vec<_>>();
+        println!("{}", cells.join("  ").trim_end());
+    }
+    println!("Total winnings: {}", ranked.iter().map(|r| r.winnings).sum::<i32>());
+    Ok(())
+}
+
+/// The selected days that have no solution.
+fn unimplemented(selection: &[u8]) -> Vec<u8> {
+    selection.iter().copied().filter(|d| days::find(*d).is_none()).collect()
+}
+
+/// Prints every problem with the ranges of a day 5 almanac, returning whether there were none.
+fn check_almanac(args: &[String]) -> Result<bool> {
+    let mut input = None;
+
+    for arg in args {
+        match arg {
+            _ if arg.starts_with("--") => return Err(Error::new(format!("unknown option '{arg}'"))),
+            _ if input.is_none() => input = Some(arg.as_str()),
+            _ => return Err(Error::new(format!("unexpected argument '{arg}'"))),
+        }
+    }
+
+    let diagnostics = diagnose(&InputSource::resolve(5, input).read()?).map_err(|e| e.with_day(5))?;
+    if diagnostics.is_empty() {
+        println!("No problems found");
+    }
+    diagnostics.iter().for_each(|d| println!("{d}"));
+    Ok(diagnostics.is_empty())
+}
+
+fn parse_selection(selection: &str) -> Option<Vec<u8>> {
+    if selection == "all" {
+        return Some(days::SOLUTIONS.iter().map(|s| s.day()).collect());
+    }
+
+    let days: Vec<u8> = if let Some((start, end)) = selection.split_once("..=") {
+        (start.parse().ok()?..=end.parse().ok()?).collect()
+    } else if let Some((start, end)) = selection.split_once("..") {
+        (start.parse().ok()?..end.parse().ok()?).collect()
+    } else {
+        vec![selection.parse().ok()?]
+    };
+
+    (!days.is_empty()).then_some(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::bench::{BenchConfig, Limit};
+    use aoc2023::output::Format;
+    use std::path::PathBuf;
+    use aoc2023::days::day7::{Card, parse_hands, RuleSet, solver};
+    use crate::{available_jobs, Options, parse_options, parse_selection, run_pool, unimplemented};
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!(parse_selection("5"), Some(vec![5]));
+        assert_eq!(parse_selection("1..=3"), Some(vec![1, 2, 3]));
+        assert_eq!(parse_selection("1..3"), Some(vec![1, 2]));
+        assert_eq!(parse_selection("all"), Some((1..=10).collect()));
+        assert_eq!(parse_selection("five"), None);
+        assert_eq!(parse_selection("1..x"), None);
+        assert_eq!(parse_selection("5..5"), None);
+        assert_eq!(parse_selection("7..3"), None);
+        assert_eq!(parse_selection("7..=3"), None);
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let bench = BenchConfig { limit: Limit::Iterations(50), ..BenchConfig::default() };
+        assert_eq!(parse_options(&args("5 --bench=50 --format=json --verify=a.toml")), Ok(Options {
+            selection: vec![5],
+            input: None,
+            jobs: 1,
+            bench: Some(bench),
+            format: Format::Json,
+            verify: Some(PathBuf::from("a.toml")),
+            history: None,
+            threshold: 10.0,
+            baseline: false,
+            set_baseline: false,
+        }));
+        assert_eq!(parse_options(&args("--bench 1..3")).map(|o| o.bench), Ok(Some(BenchConfig::default())));
+        assert_eq!(parse_options(&args("1 --verify")).map(|o| o.verify), Ok(Some(PathBuf::from("answers.toml"))));
+
+        assert!(parse_options(&args("")).is_err());
+        assert_eq!(parse_options(&args("5 example.txt")).map(|o| o.input), Ok(Some("example.txt".to_owned())));
+        assert_eq!(parse_options(&args("5 -")).map(|o| o.input), Ok(Some("-".to_owned())));
+
+        assert_eq!(parse_options(&args("all --jobs=4")).map(|o| o.jobs), Ok(4));
+        assert!(parse_options(&args("all --jobs")).is_ok_and(|o| o.jobs >= 1));
+        assert_eq!(parse_options(&args("1..=3")).map(|o| o.jobs), Ok(available_jobs()));
+        assert_eq!(parse_options(&args("3")).map(|o| o.jobs), Ok(1));
+        assert_eq!(parse_options(&args("all --bench --jobs=4")).map(|o| o.jobs), Ok(1));
+        assert_eq!(parse_options(&args("all --history")).map(|o| o.jobs), Ok(1));
+
+        let options = parse_options(&args("all --history=h.csv --threshold=25% --baseline --set-baseline")).unwrap();
+        assert_eq!((options.history, options.threshold, options.baseline, options.set_baseline),
+                   (Some(PathBuf::from("h.csv")), 25.0, true, true));
+
+        assert!(parse_options(&args("all --jobs=0")).is_err());
+        assert!(parse_options(&args("all --threshold=-5")).is_err());
+        assert!(parse_options(&args("all --baseline=yes")).is_err());
+        assert!(parse_options(&args("5 a.txt b.txt")).is_err());
+        assert!(parse_options(&args("all -")).is_err());
+        assert!(parse_options(&args("5 --fast")).is_err());
+        assert!(parse_options(&args("5 --bench=soon")).is_err());
+        assert!(parse_options(&args("5 --format=xml")).is_err());
+    }
+
+    #[test]
+    fn test_custom_rule_set() {
+        let cards = |s: &str| s.chars().map(|c| Card::try_from(c).unwrap()).collect::<Vec<_>>();
+        let jokers = RuleSet::standard().with_order(&cards("J23456789TQKA")).unwrap().with_wild(&cards("J"));
+
+        assert_eq!(jokers, RuleSet::jokers());
+        assert_eq!(jokers.clone().with_wild_in_ties(false), RuleSet::jokers_not_in_ties());
+        assert_eq!(RuleSet::standard().with_straights(true), RuleSet::poker());
+
+        let hands = parse_hands("32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483", &jokers).unwrap();
+        assert_eq!(solver(&hands, &jokers), 5905);
+
+        assert!(RuleSet::standard().with_order(&cards("23456789TJQK")).is_err());
+        assert!(RuleSet::standard().with_order(&cards("223456789TJQK")).is_err());
+    }
+
+    #[test]
+    fn test_unimplemented() {
+        assert_eq!(unimplemented(&parse_selection("all").unwrap()), []);
+        assert_eq!(unimplemented(&parse_selection("9..=12").unwrap()), [11, 12]);
+        assert_eq!(unimplemented(&parse_selection("20..=22").unwrap()), [20, 21, 22]);
+    }
+
+    #[test]
+    fn test_run_pool_keeps_order() {
+        let mut results = vec![];
+        run_pool(20, 4, |i| {
+            std::thread::sleep(std::time::Duration::from_millis((20 - i as u64) % 7));
+            i * i
+        }, |_, r| results.push(r.unwrap()));
+
+        assert_eq!(results, (0..20).map(|i| i * i).collect::<Vec<_>>());
+
+        run_pool(0, 4, |i| i, |_, _| panic!("no work expected"));
+    }
+
+    #[test]
+    fn test_run_pool_survives_panics() {
+        let mut results = vec![];
+        run_pool(6, 3, |i| {
+            if i == 2 {
+                panic!("job {i} failed");
+            }
+            i
+        }, |i, r| results.push((i, r)));
+
+        assert_eq!(results, [
+            (0, Ok(0)),
+            (1, Ok(1)),
+            (2, Err("job 2 failed".to_owned())),
+            (3, Ok(3)),
+            (4, Ok(4)),
+            (5, Ok(5)),
+        ]);
+    }
+}
@@ -1,14 +1,28 @@
 use itertools::Itertools;
+use crate::{Result, Solution};
 
-fn main() {
-    aoc2023::run_day(aoc2023::read_input_as_string, part1, part2);
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input = String;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<String> { Ok(input.to_owned()) }
+
+    fn part1(input: &String) -> u32 { part1(input) }
+
+    fn part2(input: &String) -> u32 { part2(input) }
 }
 
 fn part1(input: &str) -> u32 {
     input.lines().map(|l| {
         let mut digits = l.chars().filter_map(|c| c.to_digit(10));
         let first = digits.next().unwrap();
-        let last = digits.last().unwrap_or(first);
+        let last = digits.next_back().unwrap_or(first);
         first * 10 + last
     }).sum()
 }
@@ -35,7 +49,7 @@ fn parse_digit(input: &str) -> Option<u32> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_digit, part1, part2, TEXT_DIGITS};
+    use super::{parse_digit, part1, part2, TEXT_DIGITS};
 
     #[test]
     fn test1() {
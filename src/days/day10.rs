@@ -0,0 +1,655 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+use itertools::Itertools;
+use Direction::*;
+use Tile::*;
+use crate::{Error, Result, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Input = Loop;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Loop> { input.parse::<Map>()?.main_loop() }
+
+    fn part1(input: &Loop) -> usize { part1(input) }
+
+    fn part2(input: &Loop) -> usize { part2(input) }
+}
+
+fn part1(main_loop: &Loop) -> usize {
+    main_loop.farthest()
+}
+
+fn part2(main_loop: &Loop) -> usize {
+    main_loop.enclosed()
+}
+
+type Position = (usize, usize);
+
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+enum Tile {
+    Ground,
+    VerticalPipe,
+    HorizontalPipe,
+    NorthEastPipe,
+    NorthWestPipe,
+    SouthEastPipe,
+    SouthWestPipe,
+    Start,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '|' => VerticalPipe,
+            '-' => HorizontalPipe,
+            'L' => NorthEastPipe,
+            'J' => NorthWestPipe,
+            '7' => SouthWestPipe,
+            'F' => SouthEastPipe,
+            '.' => Ground,
+            'S' => Start,
+            _ => return Err(Error::new(format!("unknown tile '{value}'"))),
+        })
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> char {
+        match tile {
+            VerticalPipe => '|',
+            HorizontalPipe => '-',
+            NorthEastPipe => 'L',
+            NorthWestPipe => 'J',
+            SouthWestPipe => '7',
+            SouthEastPipe => 'F',
+            Ground => '.',
+            Start => 'S',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(char::from(*self))
+    }
+}
+
+impl Tile {
+    /// The tile as a box-drawing character.
+    fn box_char(&self) -> char {
+        match self {
+            VerticalPipe => '│',
+            HorizontalPipe => '─',
+            NorthEastPipe => '└',
+            NorthWestPipe => '┘',
+            SouthWestPipe => '┐',
+            SouthEastPipe => '┌',
+            Ground => '.',
+            Start => 'S',
+        }
+    }
+
+    fn output_direction(&self, direction: Direction) -> Option<Direction> {
+        match self {
+            VerticalPipe if direction.is_vertical() => Some(direction),
+            HorizontalPipe if direction.is_horizontal() => Some(direction),
+            NorthEastPipe => match direction {
+                South => Some(East),
+                West => Some(North),
+                _ => None,
+            }
+            NorthWestPipe => match direction {
+                South => Some(West),
+                East => Some(North),
+                _ => None,
+            }
+            SouthEastPipe => match direction {
+                North => Some(East),
+                West => Some(South),
+                _ => None,
+            }
+            SouthWestPipe => match direction {
+                North => Some(West),
+                East => Some(South),
+                _ => None,
+            }
+            _ => None,
+        }
+    }
+
+    /// The directions the pipe can be left in.
+    fn exits(&self) -> impl Iterator<Item = Direction> + '_ {
+        [North, East, West, South].into_iter().filter(|d| self.output_direction(d.opposite()).is_some())
+    }
+
+    /// The pipe connecting two distinct directions, given in the order north, east, west, south.
+    fn from_exits(a: Direction, b: Direction) -> Tile {
+        match (a, b) {
+            (North, East) => NorthEastPipe,
+            (North, West) => NorthWestPipe,
+            (North, South) => VerticalPipe,
+            (East, West) => HorizontalPipe,
+            (East, South) => SouthEastPipe,
+            (West, South) => SouthWestPipe,
+            _ => panic!("no pipe connects {a:?} and {b:?}"),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    fn is_horizontal(&self) -> bool { matches!(self, East | West) }
+
+    fn is_vertical(&self) -> bool { matches!(self, North | South) }
+
+    fn opposite(&self) -> Direction {
+        match self {
+            North => South,
+            South => North,
+            East => West,
+            West => East,
+        }
+    }
+}
+
+/// How the pipes of a rendered maze are drawn.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Style {
+    /// The `|-LJ7F` characters of the puzzle input.
+    Ascii,
+    /// Box-drawing characters.
+    Unicode,
+}
+
+/// Where a tile lies relative to the main loop.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Region {
+    Loop,
+    Inside,
+    Outside,
+}
+
+/// The pipe maze, with the start tile replaced by the pipe it hides.
+pub struct Map {
+    map: Vec<Vec<Tile>>,
+    start: Position,
+}
+
+impl Map {
+    fn tile(&self, (row, col): Position) -> Tile {
+        self.map[row][col]
+    }
+
+    /// Replaces the start tile with the only pipe that connects it to exactly two neighbours.
+    fn resolve_start(&mut self) -> Result<()> {
+        let connections = [North, East, West, South].into_iter()
+            .filter(|&d| self.move_position(self.start, d)
+                .is_some_and(|p| self.tile(p).output_direction(d).is_some()))
+            .collect_vec();
+
+        let [a, b] = connections[..] else {
+            return Err(Error::new(format!("start tile connects to {} neighbours, expected 2", connections.len()))
+                .with_line(self.start.0 + 1)
+                .with_column(self.start.1 + 1));
+        };
+
+        self.map[self.start.0][self.start.1] = Tile::from_exits(a, b);
+        Ok(())
+    }
+
+    /// Walks the loop through the start tile, failing at the first pipe that leads off the map or
+    /// into a tile that does not connect back.
+    pub fn main_loop(&self) -> Result<Loop> {
+        let at = |(row, col): Position, message: String| Error::new(message).with_line(row + 1).with_column(col + 1);
+
+        let mut position = self.start;
+        let mut direction = self.tile(position).exits().next().unwrap();
+
+        let mut steps = vec![];
+        loop {
+            steps.push((position, direction));
+            let from = position;
+            position = self.move_position(position, direction)
+                .ok_or_else(|| at(from, format!("loop leads off the map going {direction:?}")))?;
+            if position == self.start {
+                break;
+            }
+            direction = self.tile(position).output_direction(direction)
+                .ok_or_else(|| at(position, format!("loop is broken, tile does not connect to the {:?}", direction.opposite())))?;
+        }
+
+        let members = steps.iter().map(|(p, _)| *p).collect();
+        Ok(Loop { steps, members })
+    }
+
+    /// Classifies every tile by scanning each row and flipping between outside and inside whenever
+    /// the loop is crossed, which happens at every loop pipe with an exit to the north.
+    fn regions(&self, main_loop: &Loop) -> Vec<Vec<Region>> {
+        self.map.iter()
+            .enumerate()
+            .map(|(row, tiles)| {
+                let mut inside = false;
+                tiles.iter()
+                    .enumerate()
+                    .map(|(col, tile)| if main_loop.contains((row, col)) {
+                        inside ^= tile.exits().contains(&North);
+                        Region::Loop
+                    } else if inside {
+                        Region::Inside
+                    } else {
+                        Region::Outside
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Draws the main loop in the given style, with every other tile marked `I` if it is inside
+    /// the loop and `O` if it is outside.
+    pub fn render(&self, main_loop: &Loop, style: Style) -> String {
+        self.regions(main_loop).iter()
+            .enumerate()
+            .map(|(row, regions)| regions.iter()
+                .enumerate()
+                .map(|(col, region)| match region {
+                    _ if (row, col) == self.start => 'S',
+                    Region::Loop if style == Style::Ascii => char::from(self.tile((row, col))),
+                    Region::Loop => self.tile((row, col)).box_char(),
+                    Region::Inside => 'I',
+                    Region::Outside => 'O',
+                })
+                .collect::<String>())
+            .join("\n")
+    }
+
+    /// Draws the maze as an SVG image: inside tiles green, outside tiles grey, pipes that are not
+    /// part of the loop as thin lines and the loop itself as a thick red line.
+    pub fn to_svg(&self, main_loop: &Loop) -> String {
+        const CELL: usize = 10;
+        let center = |(row, col): Position| (col * CELL + CELL / 2, row * CELL + CELL / 2);
+
+        let (height, width) = (self.map.len() * CELL, self.map.first().map_or(0, Vec::len) * CELL);
+        let mut svg = format!(r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#);
+        svg.push('\n');
+
+        for (row, regions) in self.regions(main_loop).iter().enumerate() {
+            for (col, region) in regions.iter().enumerate() {
+                let fill = match region {
+                    Region::Loop => continue,
+                    Region::Inside => "#8fd18f",
+                    Region::Outside => "#eeeeee",
+                };
+                writeln!(svg, r#"<rect x="{}" y="{}" width="{CELL}" height="{CELL}" fill="{fill}"/>"#, col * CELL, row * CELL).unwrap();
+
+                let (x, y) = center((row, col));
+                for exit in self.tile((row, col)).exits() {
+                    let (to_x, to_y) = match exit {
+                        North => (x, y - CELL / 2),
+                        South => (x, y + CELL / 2),
+                        East => (x + CELL / 2, y),
+                        West => (x - CELL / 2, y),
+                    };
+                    writeln!(svg, r##"<line x1="{x}" y1="{y}" x2="{to_x}" y2="{to_y}" stroke="#999999"/>"##).unwrap();
+                }
+            }
+        }
+
+        let points = main_loop.positions().map(|p| {
+            let (x, y) = center(p);
+            format!("{x},{y}")
+        }).join(" ");
+        writeln!(svg, r##"<polygon points="{points}" fill="none" stroke="#d03030" stroke-width="3" stroke-linejoin="round"/>"##).unwrap();
+
+        let (x, y) = center(self.start);
+        writeln!(svg, r##"<circle cx="{x}" cy="{y}" r="{}" fill="#d03030"/>"##, CELL / 3).unwrap();
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn move_position(&self, (row, col): Position, direction: Direction) -> Option<Position> {
+        match direction {
+            North if row > 0 => Some((row - 1, col)),
+            South if row < self.map.len() - 1 => Some((row + 1, col)),
+            East  if col < self.map.first().unwrap().len() - 1 => Some((row, col + 1)),
+            West if col > 0 => Some((row, col - 1)),
+            _ => None
+        }
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (row, tiles) in self.map.iter().enumerate() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for (col, tile) in tiles.iter().enumerate() {
+                if (row, col) == self.start { write!(f, "{Start}")? } else { write!(f, "{tile}")? }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The main loop through the start tile.
+pub struct Loop {
+    steps: Vec<(Position, Direction)>,
+    members: HashSet<Position>,
+}
+
+impl Loop {
+    /// Every position of the loop in walking order, starting at the start tile, with the direction
+    /// the loop leaves it in.
+    pub fn steps(&self) -> &[(Position, Direction)] {
+        &self.steps
+    }
+
+    /// Every position of the loop in walking order, starting at the start tile.
+    pub fn positions(&self) -> impl ExactSizeIterator<Item = Position> + Clone + '_ {
+        self.steps.iter().map(|(p, _)| *p)
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        self.members.contains(&position)
+    }
+
+    fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// The number of steps to the position farthest from the start along the loop.
+    fn farthest(&self) -> usize {
+        self.len() / 2
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle containing the loop.
+    pub fn bounding_box(&self) -> (Position, Position) {
+        let (top, bottom) = self.positions().map(|p| p.0).minmax().into_option().unwrap();
+        let (left, right) = self.positions().map(|p| p.1).minmax().into_option().unwrap();
+        ((top, left), (bottom, right))
+    }
+
+    /// The number of tiles strictly inside the loop.
+    fn enclosed(&self) -> usize {
+        // Shoelace formula for twice the area enclosed by the loop, then Pick's theorem to count the
+        // tiles strictly inside it. Tiles squeezed between pipes are outside the polygon on their own.
+        let double_area = self.positions()
+            .circular_tuple_windows()
+            .map(|(a, b)| a.0 as isize * b.1 as isize - b.0 as isize * a.1 as isize)
+            .sum::<isize>()
+            .unsigned_abs();
+
+        (double_area - self.len()) / 2 + 1
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = s.lines()
+            .enumerate()
+            .map(|(row, l)| l.chars()
+                .enumerate()
+                .map(|(col, c)| Tile::try_from(c).map_err(|e| e.with_line(row + 1).with_column(col + 1)))
+                .collect::<Result<Vec<_>>>())
+            .collect::<Result<Vec<Vec<_>>>>()?;
+
+        let width = map.first().map_or(0, Vec::len);
+        if let Some((row, l)) = map.iter().enumerate().find(|(_, l)| l.len() != width) {
+            return Err(Error::new(format!("expected {width} tiles, found {}", l.len()))
+                .with_line(row + 1)
+                .with_column(width.min(l.len()) + 1));
+        }
+
+        let mut starts = map.iter()
+            .enumerate()
+            .flat_map(|(r, l)| l.iter().positions(|t| *t == Start).map(move |c| (r, c)));
+        let start = starts.next().ok_or_else(|| Error::new("missing start tile"))?;
+        if let Some((row, col)) = starts.next() {
+            return Err(Error::new("more than one start tile").with_line(row + 1).with_column(col + 1));
+        }
+
+        let mut map = Map { map, start };
+        map.resolve_start()?;
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+    use super::{Direction::*, Loop, Map, part1, part2, Region, Style, Tile, Tile::*};
+
+    fn main_loop(input: &str) -> Loop {
+        input.parse::<Map>().unwrap().main_loop().unwrap()
+    }
+
+    const EXAMPLE_INPUT_1: &str = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+
+    const EXAMPLE_INPUT_2: &str = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&main_loop(EXAMPLE_INPUT_1)), 4);
+        assert_eq!(part1(&main_loop(EXAMPLE_INPUT_2)), 8);
+    }
+
+    const EXAMPLE_INPUT_3: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    const EXAMPLE_INPUT_4: &str = "..........
+.S------7.
+.|F----7|.
+.||OOOO||.
+.||OOOO||.
+.|L-7F-J|.
+.|II||II|.
+.L--JL--J.
+..........";
+
+    const EXAMPLE_INPUT_5: &str = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+    const EXAMPLE_INPUT_6: &str = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    #[test]
+    fn test_part2() {
+        let loops = [EXAMPLE_INPUT_1, EXAMPLE_INPUT_2, EXAMPLE_INPUT_3, EXAMPLE_INPUT_5, EXAMPLE_INPUT_6].map(main_loop);
+        assert_eq!(loops.map(|l| part2(&l)), [1, 1, 4, 8, 10]);
+    }
+
+    #[test]
+    fn test_part2_squeezing() {
+        // The tiles marked O are outside the loop even though they can only be reached by
+        // squeezing between the pipes.
+        let main_loop = main_loop(&EXAMPLE_INPUT_4.replace(['O', 'I'], "."));
+        assert_eq!(part2(&main_loop), EXAMPLE_INPUT_4.matches('I').count());
+    }
+
+    #[test]
+    fn test_main_loop() {
+        let main_loop = main_loop(EXAMPLE_INPUT_1);
+
+        assert_eq!(main_loop.steps(), [
+            ((1, 1), East), ((1, 2), East), ((1, 3), South), ((2, 3), South),
+            ((3, 3), West), ((3, 2), West), ((3, 1), North), ((2, 1), North),
+        ]);
+        assert!(main_loop.contains((2, 3)));
+        assert!(!main_loop.contains((2, 2)));
+        assert_eq!(main_loop.bounding_box(), ((1, 1), (3, 3)));
+        assert_eq!(self::main_loop(EXAMPLE_INPUT_2).bounding_box(), ((0, 0), (4, 4)));
+    }
+
+    #[test]
+    fn test_broken_loop() {
+        let main_loop = |input: &str| input.parse::<Map>().unwrap().main_loop().err();
+
+        assert_eq!(main_loop("S7\n||"), Some(Error::new("loop leads off the map going South").with_line(2).with_column(2)));
+        assert_eq!(main_loop("S7\n|.\nL-"), Some(Error::new("loop is broken, tile does not connect to the North").with_line(2).with_column(2)));
+    }
+
+    #[test]
+    fn test_map_start() {
+        let map1 = EXAMPLE_INPUT_1.parse::<Map>().unwrap();
+        assert_eq!(map1.start, (1, 1));
+
+        let map2 = EXAMPLE_INPUT_2.parse::<Map>().unwrap();
+        assert_eq!(map2.start, (2, 0));
+    }
+
+    #[test]
+    fn test_map_move_direction() {
+        let map = EXAMPLE_INPUT_1.parse::<Map>().unwrap();
+        assert_eq!(map.move_position((1, 1), North), Some((0, 1)));
+        assert_eq!(map.move_position((1, 1), South), Some((2, 1)));
+        assert_eq!(map.move_position((1, 1), East), Some((1, 2)));
+        assert_eq!(map.move_position((1, 1), West), Some((1, 0)));
+
+        assert_eq!(map.move_position((0, 1), North), None);
+        assert_eq!(map.move_position((4, 1), South), None);
+        assert_eq!(map.move_position((1, 4), East), None);
+        assert_eq!(map.move_position((1, 0), West), None);
+    }
+
+    #[test]
+    fn test_pipe_directions() {
+        let map = EXAMPLE_INPUT_1.parse::<Map>().unwrap();
+
+        assert_eq!(map.map[0][0].output_direction(North), None);
+        assert_eq!(map.map[0][0].output_direction(South), None);
+        assert_eq!(map.map[0][0].output_direction(West), None);
+        assert_eq!(map.map[0][0].output_direction(East), None);
+
+        // Clock-wise
+        assert_eq!(map.map[1][2].output_direction(East), Some(East));
+        assert_eq!(map.map[1][3].output_direction(East), Some(South));
+        assert_eq!(map.map[2][3].output_direction(South), Some(South));
+        assert_eq!(map.map[3][3].output_direction(South), Some(West));
+        assert_eq!(map.map[3][2].output_direction(West), Some(West));
+        assert_eq!(map.map[3][1].output_direction(West), Some(North));
+        assert_eq!(map.map[2][1].output_direction(North), Some(North));
+        assert_eq!(map.map[1][1].output_direction(North), Some(East));
+    }
+
+    #[test]
+    fn test_resolve_start() {
+        let tile = |input: &str| input.parse::<Map>().map(|map| map.tile(map.start));
+
+        assert_eq!(tile(EXAMPLE_INPUT_1), Ok(SouthEastPipe));
+        assert_eq!(tile(EXAMPLE_INPUT_2), Ok(SouthEastPipe));
+        assert_eq!(tile(EXAMPLE_INPUT_6), Ok(SouthWestPipe));
+        assert_eq!(tile(".|.\n-S.\n..."), Ok(NorthWestPipe));
+        assert_eq!(tile("|\nS\n|"), Ok(VerticalPipe));
+        assert_eq!(tile("-S-"), Ok(HorizontalPipe));
+
+        assert_eq!(tile("...\n.S.\n..."), Err(Error::new("start tile connects to 0 neighbours, expected 2").with_line(2).with_column(2)));
+        assert_eq!(tile(".|.\n-S-\n.|."), Err(Error::new("start tile connects to 4 neighbours, expected 2").with_line(2).with_column(2)));
+        assert_eq!(tile("F7.\nS|."), Err(Error::new("start tile connects to 1 neighbours, expected 2").with_line(2).with_column(1)));
+        assert_eq!(tile("..."), Err(Error::new("missing start tile")));
+        assert_eq!(tile("S-S"), Err(Error::new("more than one start tile").with_line(1).with_column(3)));
+    }
+
+    #[test]
+    fn test_tile_chars() {
+        for c in "|-LJ7F.S".chars() {
+            assert_eq!(Tile::try_from(c).map(char::from), Ok(c));
+        }
+
+        for input in [EXAMPLE_INPUT_1, EXAMPLE_INPUT_2, EXAMPLE_INPUT_6] {
+            assert_eq!(input.parse::<Map>().unwrap().to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_regions() {
+        for input in [EXAMPLE_INPUT_1, EXAMPLE_INPUT_2, EXAMPLE_INPUT_3, EXAMPLE_INPUT_5, EXAMPLE_INPUT_6] {
+            let map = input.parse::<Map>().unwrap();
+            let main_loop = map.main_loop().unwrap();
+            let inside = map.regions(&main_loop).iter().flatten().filter(|r| **r == Region::Inside).count();
+            assert_eq!(inside, main_loop.enclosed());
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let map = EXAMPLE_INPUT_1.parse::<Map>().unwrap();
+        let main_loop = map.main_loop().unwrap();
+
+        assert_eq!(map.render(&main_loop, Style::Ascii), "OOOOO\nOS-7O\nO|I|O\nOL-JO\nOOOOO");
+        assert_eq!(map.render(&main_loop, Style::Unicode), "OOOOO\nOS─┐O\nO│I│O\nO└─┘O\nOOOOO");
+
+        let map = EXAMPLE_INPUT_4.replace(['O', 'I'], ".").parse::<Map>().unwrap();
+        assert_eq!(map.render(&map.main_loop().unwrap(), Style::Ascii), EXAMPLE_INPUT_4.replace('.', "O"));
+    }
+
+    #[test]
+    fn test_svg() {
+        let map = EXAMPLE_INPUT_1.parse::<Map>().unwrap();
+        let svg = map.to_svg(&map.main_loop().unwrap());
+
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="50" height="50""#));
+        assert_eq!(svg.matches("<rect").count(), 17);
+        assert_eq!(svg.matches(r##"fill="#8fd18f""##).count(), 1);
+        assert!(svg.contains(r#"<polygon points="15,15 25,15 35,15 35,25 35,35 25,35 15,35 15,25""#));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(".....\n.S-7.\n.|x|.".parse::<Map>().err(), Some(Error::new("unknown tile 'x'").with_line(3).with_column(3)));
+        assert_eq!("S7\n|".parse::<Map>().err(), Some(Error::new("expected 2 tiles, found 1").with_line(2).with_column(2)));
+        assert_eq!("S7\n|J.".parse::<Map>().err(), Some(Error::new("expected 2 tiles, found 3").with_line(2).with_column(3)));
+    }
+}
\ No newline at end of file
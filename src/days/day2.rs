@@ -1,8 +1,22 @@
 use std::cmp::max;
 use std::str::FromStr;
+use crate::{column_of, Error, parse_field, Result, Solution, split_once};
 
-fn main() {
-    aoc2023::run_day(aoc2023::parse_lines_from_stdin, part1, part2);
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input = Vec<Game>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Game>> { crate::parse_lines_from_str(input) }
+
+    fn part1(input: &Vec<Game>) -> usize { part1(input) }
+
+    fn part2(input: &Vec<Game>) -> usize { part2(input) }
 }
 
 fn part1(games: &[Game]) -> usize {
@@ -19,7 +33,7 @@ fn part2(games: &[Game]) -> usize {
 }
 
 #[derive(Debug, Eq, PartialEq)]
-struct Game {
+pub struct Game {
     id: usize,
     reveals: Vec<Reveal>,
 }
@@ -39,14 +53,18 @@ impl Game {
 }
 
 impl FromStr for Game {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (header, reveals) = s.split_once(": ").unwrap();
+        let (header, reveals) = split_once(s, ": ")?;
+        let id = header.strip_prefix("Game ")
+            .ok_or_else(|| Error::at(s, header, "expected 'Game <id>'"))?;
 
         Ok(Game {
-            id: header[5..].parse().unwrap(),
-            reveals: reveals.split("; ").map(|r| r.parse().unwrap()).collect(),
+            id: parse_field(s, id)?,
+            reveals: reveals.split("; ")
+                .map(|r| r.parse().map_err(|e: Error| e.shift_column(column_of(s, r) - 1)))
+                .collect::<Result<_>>()?,
         })
     }
 }
@@ -69,21 +87,21 @@ impl Reveal {
 }
 
 impl FromStr for Reveal {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut reveal = Reveal::default();
 
-        s.split(", ").for_each(|p| {
-            let (amount, color) = p.split_once(' ').unwrap();
-            let amount = amount.parse::<usize>().unwrap();
+        for p in s.split(", ") {
+            let (amount, color) = split_once(p, " ").map_err(|e| e.with_column(column_of(s, p)))?;
+            let amount = parse_field::<usize>(s, amount)?;
             match color {
                 "red" => reveal.red += amount,
                 "green" => reveal.green += amount,
                 "blue" => reveal.blue += amount,
-                _ => panic!("unknown color")
+                _ => return Err(Error::at(s, color, format!("unknown color '{color}'"))),
             }
-        });
+        }
 
         Ok(reveal)
     }
@@ -91,7 +109,8 @@ impl FromStr for Reveal {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Game, part1, part2, Reveal};
+    use crate::Error;
+    use super::{Game, part1, part2, Reveal};
 
     const EXAMPLE_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
@@ -143,4 +162,12 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
             "Game 13: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green".parse().unwrap()
         );
     }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("Game 1: 3 blue, 4 purple".parse::<Game>(), Err(Error::new("unknown color 'purple'").with_column(19)));
+        assert_eq!("Game x: 3 blue".parse::<Game>(), Err(Error::new("invalid number 'x'").with_column(6)));
+        assert_eq!("Game 1: 3 blue; 4".parse::<Game>(), Err(Error::new("expected ' '").with_column(17)));
+        assert_eq!("Round 1: 3 blue".parse::<Game>(), Err(Error::new("expected 'Game <id>'").with_column(1)));
+    }
 }
\ No newline at end of file
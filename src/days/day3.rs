@@ -1,7 +1,21 @@
 use itertools::Itertools;
+use crate::{Result, Solution};
 
-fn main() {
-    aoc2023::run_day(|| parse_schematic(&aoc2023::read_input_as_string()), part1, part2);
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input = (Vec<Part>, Vec<Label>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<(Vec<Part>, Vec<Label>)> { Ok(parse_schematic(input)) }
+
+    fn part1(input: &(Vec<Part>, Vec<Label>)) -> usize { part1(input) }
+
+    fn part2(input: &(Vec<Part>, Vec<Label>)) -> usize { part2(input) }
 }
 
 fn part1((parts, labels): &(Vec<Part>, Vec<Label>)) -> usize {
@@ -39,7 +53,7 @@ fn parse_schematic(input: &str) -> (Vec<Part>, Vec<Label>) {
 }
 
 #[derive(Debug)]
-struct Part {
+pub struct Part {
     row: usize,
     column: usize,
 }
@@ -58,7 +72,7 @@ impl Part {
 }
 
 #[derive(Debug)]
-struct Label {
+pub struct Label {
     number: usize,
     row: usize,
     column: usize,
@@ -90,7 +104,7 @@ fn try_read_digits(input: &str) -> Option<(usize, usize)> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{try_read_digits, parse_schematic, part1, part2, Label, Part};
+    use super::{try_read_digits, parse_schematic, part1, part2, Label, Part};
 
     const EXAMPLE_INPUT: &str = "467..114..
 ...*......
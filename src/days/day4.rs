@@ -1,8 +1,22 @@
 use std::collections::HashSet;
 use std::str::FromStr;
+use crate::{Error, parse_field, Result, Solution, split_once};
 
-fn main() {
-    aoc2023::run_day(aoc2023::parse_lines_from_stdin, part1, part2);
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input = Vec<ScratchCard>;
+    type Output1 = u32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<ScratchCard>> { crate::parse_lines_from_str(input) }
+
+    fn part1(input: &Vec<ScratchCard>) -> u32 { part1(input) }
+
+    fn part2(input: &Vec<ScratchCard>) -> usize { part2(input) }
 }
 
 fn part1(cards: &[ScratchCard]) -> u32 {
@@ -29,7 +43,7 @@ fn part2(cards: &[ScratchCard]) -> usize {
 }
 
 #[derive(Debug)]
-struct ScratchCard(usize);
+pub struct ScratchCard(usize);
 
 impl ScratchCard {
     fn won_count(&self) -> usize {
@@ -45,26 +59,27 @@ impl ScratchCard {
 }
 
 impl FromStr for ScratchCard {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, body) = s.split_once(": ").unwrap();
-        let (winning_numbers, numbers) = body.split_once(" | ").unwrap();
+        let (_, body) = split_once(s, ": ")?;
+        let (winning_numbers, numbers) = split_once(body, " | ")?;
 
         let winning_numbers = winning_numbers.split_whitespace()
-            .map(|n| n.parse::<u32>().unwrap())
-            .collect::<HashSet<_>>();
+            .map(|n| parse_field::<u32>(s, n))
+            .collect::<Result<HashSet<_>>>()?;
+
+        let numbers = numbers.split_whitespace()
+            .map(|n| parse_field::<u32>(s, n))
+            .collect::<Result<Vec<_>>>()?;
 
-        Ok(ScratchCard(numbers.split_whitespace()
-            .map(|n| n.parse().unwrap())
-            .filter(|n| winning_numbers.contains(n))
-            .count()))
+        Ok(ScratchCard(numbers.iter().filter(|n| winning_numbers.contains(n)).count()))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{part1, part2, ScratchCard};
+    use super::{part1, part2, ScratchCard};
 
     const EXAMPLE_INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
@@ -75,18 +90,18 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&aoc2023::parse_lines_from_str(EXAMPLE_INPUT)), 13);
+        assert_eq!(part1(&crate::parse_lines_from_str(EXAMPLE_INPUT).unwrap()), 13);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&aoc2023::parse_lines_from_str(EXAMPLE_INPUT)), 30);
+        assert_eq!(part2(&crate::parse_lines_from_str(EXAMPLE_INPUT).unwrap()), 30);
     }
 
     #[test]
     fn test_scratch_card_score() {
         let expected_score = [8, 2, 2, 1, 0, 0];
-        let cards = aoc2023::parse_lines_from_str::<ScratchCard>(EXAMPLE_INPUT);
+        let cards = crate::parse_lines_from_str::<ScratchCard>(EXAMPLE_INPUT).unwrap();
 
         for (card, score) in cards.iter().zip(&expected_score) {
             assert_eq!(card.score(), *score);
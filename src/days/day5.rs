@@ -0,0 +1,766 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::{Error, parse_field, Result, Solution, split_once};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input = InputContext;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<InputContext> { input.parse() }
+
+    fn part1(input: &InputContext) -> usize { part1(input) }
+
+    fn part2(input: &InputContext) -> usize { part2(input) }
+}
+
+fn part1(input: &InputContext) -> usize {
+    input.seeds.iter().map(|s| input.location_map.map(*s)).min().unwrap()
+}
+
+fn part2(input: &InputContext) -> usize {
+    input.seed_intervals()
+        .flat_map(|seeds| input.location_map.map_interval(seeds))
+        .map(|locations| locations.start)
+        .min().unwrap()
+}
+
+/// Maps every seed of every range on its own, as a reference for the interval-based [`part2`].
+#[cfg(test)]
+fn part2_per_seed(input: &InputContext) -> usize {
+    input.seed_intervals()
+        .flatten()
+        .map(|s| input.map_to_location(s)).min().unwrap()
+}
+
+/// A half-open interval of numbers.
+type Interval = std::ops::Range<usize>;
+
+pub struct InputContext {
+    seeds: Vec<usize>,
+    /// The maps keyed by the category they map from.
+    graph: HashMap<String, Vec<RangeCollection>>,
+    /// The maps from seed to location composed into one.
+    location_map: RangeCollection,
+}
+
+impl InputContext {
+    /// Maps a seed through every map in turn, as a reference for the composed location map.
+    #[cfg(test)]
+    fn map_to_location(&self, seed: usize) -> usize {
+        self.convert("seed", "location", seed).unwrap()
+    }
+
+    /// The seed numbers read as pairs of start and length, as in part 2.
+    fn seed_intervals(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.seeds.iter().tuples().map(|(start, length)| *start..start + length)
+    }
+
+    /// Converts `value` from category `from` to category `to` along the only path of maps
+    /// between them.
+    pub fn convert(&self, from: &str, to: &str, value: usize) -> Result<usize> {
+        Ok(self.path(from, to)?.iter().fold(value, |acc, m| m.map(acc)))
+    }
+
+    /// Every value of category `from` that converts to `value` of category `to`, smallest first.
+    pub fn trace(&self, from: &str, to: &str, value: usize) -> Result<Vec<usize>> {
+        Ok(self.path(from, to)?.iter().rev().fold(vec![value], |values, m| values.into_iter()
+            .flat_map(|v| m.unmap(v))
+            .sorted_unstable()
+            .dedup()
+            .collect()))
+    }
+
+    /// Every seed that ends up at `location`, smallest first, whether or not it is in the almanac.
+    pub fn trace_location(&self, location: usize) -> Vec<usize> {
+        self.location_map.unmap(location)
+    }
+
+    /// The maps from seed to location composed into one, one range per line, leaving out the
+    /// numbers that map to themselves.
+    pub fn location_map(&self) -> impl Display + '_ {
+        &self.location_map
+    }
+
+    /// The maps leading from category `from` to `to`, which must be connected by exactly one path.
+    fn path(&self, from: &str, to: &str) -> Result<Vec<&RangeCollection>> {
+        let mut paths = vec![];
+        self.find_paths(from, to, &mut vec![], &mut paths);
+
+        match paths.len() {
+            0 => Err(Error::new(format!("no path of maps from {from} to {to}"))),
+            1 => Ok(paths.pop().unwrap()),
+            n => Err(Error::new(format!("{n} paths of maps from {from} to {to}, expected one"))),
+        }
+    }
+
+    fn find_paths<'a>(&'a self, from: &str, to: &str, path: &mut Vec<&'a RangeCollection>, paths: &mut Vec<Vec<&'a RangeCollection>>) {
+        if from == to {
+            paths.push(path.clone());
+            return;
+        }
+
+        for map in self.graph.get(from).into_iter().flatten() {
+            // Never visit a category twice, so cycles in the graph end the search.
+            if map.destination == from || path.iter().any(|m| m.source == map.destination) {
+                continue;
+            }
+
+            path.push(map);
+            self.find_paths(&map.destination, to, path, paths);
+            path.pop();
+        }
+    }
+}
+
+impl FromStr for InputContext {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (seeds_line, maps) = split_once(s, "\n\n").map_err(|e| e.with_line(1))?;
+        let seeds = seeds_line.strip_prefix("seeds:")
+            .ok_or_else(|| Error::new("expected 'seeds:'").with_line(1).with_column(1))?;
+
+        let (maps, diagnostics) = parse_maps(maps)?;
+        if let Some(first) = diagnostics.first() {
+            let more = match diagnostics.len() {
+                1 => String::new(),
+                2 => " (and 1 more problem)".to_owned(),
+                n => format!(" (and {} more problems)", n - 1),
+            };
+            return Err(Error::new(format!("{}{more}", first.problem)).with_line(first.line));
+        }
+
+        let mut graph = HashMap::<_, Vec<RangeCollection>>::new();
+        for map in maps {
+            graph.entry(map.source.clone()).or_default().push(map);
+        }
+
+        let seeds = seeds.split_whitespace()
+            .map(|n| parse_field(seeds_line, n).map_err(|e| e.with_line(1)))
+            .collect::<Result<Vec<_>>>()?;
+        if seeds.is_empty() || seeds.len() % 2 != 0 {
+            return Err(Error::new(format!("expected pairs of seed starts and lengths, found {} numbers", seeds.len())).with_line(1));
+        }
+
+        let mut input = InputContext {
+            seeds,
+            graph,
+            location_map: RangeCollection::default(),
+        };
+        input.location_map = input.path("seed", "location")?.into_iter()
+            .fold(RangeCollection::new("seed".to_owned(), "seed".to_owned(), []), |acc, m| acc.then(m));
+
+        Ok(input)
+    }
+}
+
+/// Splits the maps of an almanac into blocks, each with the line of the almanac it starts on.
+fn blocks(maps: &str) -> impl Iterator<Item = (usize, &str)> {
+    maps.split("\n\n").scan(3, |line, block| {
+        let start = *line;
+        *line += block.lines().count() + 1;
+        Some((start, block))
+    })
+}
+
+/// Every problem with the ranges of an almanac's maps. Parsing rejects an almanac with any of
+/// them, so this only fails on almanacs that are malformed otherwise.
+pub fn diagnose(input: &str) -> Result<Vec<Diagnostic>> {
+    let (_, maps) = split_once(input, "\n\n").map_err(|e| e.with_line(1))?;
+    Ok(parse_maps(maps)?.1)
+}
+
+/// Parses the maps of an almanac along with every problem with their ranges.
+fn parse_maps(maps: &str) -> Result<(Vec<RangeCollection>, Vec<Diagnostic>)> {
+    let mut collections = vec![];
+    let mut diagnostics = vec![];
+
+    for (line, block) in blocks(maps) {
+        let (source, destination, ranges) = RangeCollection::parse_block(block).map_err(|e| e.shift_line(line - 1))?;
+        diagnostics.extend(validate(&ranges).into_iter().map(|d| d.shift_line(line - 1)));
+        collections.push(RangeCollection::new(source.to_owned(), destination.to_owned(), ranges.into_iter().map(|(_, r)| r)));
+    }
+
+    Ok((collections, diagnostics))
+}
+
+/// Finds the zero-length ranges, the ranges whose end does not fit in a `usize` and the ranges
+/// whose sources overlap an earlier one, given the ranges with their line numbers.
+fn validate(ranges: &[LineRange]) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut valid = vec![];
+
+    for (line, range) in ranges {
+        if range.length == 0 {
+            diagnostics.push(Diagnostic { line: *line, problem: Problem::ZeroLength });
+        } else if range.source_start.checked_add(range.length).is_none()
+            || range.destination_start.checked_add(range.length).is_none() {
+            diagnostics.push(Diagnostic { line: *line, problem: Problem::Overflow });
+        } else {
+            valid.push((*line, range));
+        }
+    }
+
+    // The end and line of the range reaching furthest so far, in order of source start.
+    valid.sort_by_key(|(line, r)| (r.source_start, *line));
+    let mut furthest: Option<(usize, usize)> = None;
+    for (line, range) in valid {
+        if let Some((end, other)) = furthest.filter(|(end, _)| range.source_start < *end) {
+            diagnostics.push(Diagnostic { line, problem: Problem::Overlap { line: other } });
+            if range.source_start + range.length <= end {
+                continue;
+            }
+        }
+        furthest = Some((range.source_start + range.length, line));
+    }
+
+    diagnostics.sort_by_key(|d| d.line);
+    diagnostics
+}
+
+/// A range with the line it was read from.
+type LineRange = (usize, Range);
+
+/// A problem with a range of a map, at the line it is on.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub problem: Problem,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Problem {
+    /// The sources overlap those of the range on another line.
+    Overlap { line: usize },
+    ZeroLength,
+    /// The end of the source or destination range does not fit in a `usize`.
+    Overflow,
+}
+
+impl Diagnostic {
+    fn shift_line(self, by: usize) -> Diagnostic {
+        let problem = match self.problem {
+            Problem::Overlap { line } => Problem::Overlap { line: line + by },
+            problem => problem,
+        };
+        Diagnostic { line: self.line + by, problem }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Overlap { line } => write!(f, "source range overlaps the one on line {line}"),
+            Problem::ZeroLength => write!(f, "range has length 0"),
+            Problem::Overflow => write!(f, "range end overflows usize"),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.problem)
+    }
+}
+
+impl From<Diagnostic> for Error {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Error::new(diagnostic.problem.to_string()).with_line(diagnostic.line)
+    }
+}
+
+/// A map from one category to another, read from a `<source>-to-<destination> map:` block.
+#[derive(Debug, Default)]
+struct RangeCollection {
+    source: String,
+    destination: String,
+    ranges: Vec<Range>,
+}
+
+impl RangeCollection {
+    fn new<T: IntoIterator<Item=Range>>(source: String, destination: String, iterator: T) -> Self {
+        let mut ranges = iterator.into_iter().collect::<Vec<_>>();
+        ranges.sort_by_key(|r| r.source_start);
+        RangeCollection { source, destination, ranges }
+    }
+
+    fn map(&self, source: usize) -> usize {
+        let index = match self.ranges.binary_search_by_key(&source, |r| r.source_start) {
+            Ok(i) => i,
+            Err(i) => i.saturating_sub(1),
+        };
+        self.ranges.get(index).and_then(|r| r.map(source)).unwrap_or(source)
+    }
+
+    /// Parses a block that follows `offset` other lines, rejecting ranges with any problem.
+    fn parse_at(s: &str, offset: usize) -> Result<RangeCollection> {
+        let (source, destination, ranges) = RangeCollection::parse_block(s).map_err(|e| e.shift_line(offset))?;
+        if let Some(diagnostic) = validate(&ranges).into_iter().next() {
+            return Err(diagnostic.shift_line(offset).into());
+        }
+
+        Ok(RangeCollection::new(source.to_owned(), destination.to_owned(), ranges.into_iter().map(|(_, r)| r)))
+    }
+
+    /// Reads the categories of a block and its ranges with their line numbers, without validating.
+    fn parse_block(s: &str) -> Result<(&str, &str, Vec<LineRange>)> {
+        let header = s.lines().next().unwrap_or_default();
+        let (source, destination) = header.strip_suffix(" map:")
+            .and_then(|h| h.split_once("-to-"))
+            .ok_or_else(|| Error::new(format!("expected '<source>-to-<destination> map:', found '{header}'")).with_line(1))?;
+
+        let ranges = s.lines()
+            .enumerate()
+            .skip(1)
+            .map(|(i, l)| l.parse().map(|r| (i + 1, r)).map_err(|e: Error| e.with_line(i + 1)))
+            .collect::<Result<_>>()?;
+
+        Ok((source, destination, ranges))
+    }
+
+    /// Every source number that maps to `destination`: those the ranges map there, plus
+    /// `destination` itself if no range covers it and it therefore maps to itself.
+    fn unmap(&self, destination: usize) -> Vec<usize> {
+        let mut sources = self.ranges.iter().filter_map(|r| r.unmap(destination)).collect_vec();
+        if self.ranges.iter().all(|r| r.map(destination).is_none()) {
+            sources.push(destination);
+        }
+        sources.sort_unstable();
+        sources
+    }
+
+    /// Maps every number of `interval`, splitting it wherever it crosses the boundary of a range.
+    /// Parts not covered by any range map to themselves. Empty intervals map to nothing.
+    fn map_interval(&self, interval: Interval) -> Vec<Interval> {
+        let mut mapped = vec![];
+        let mut start = interval.start;
+
+        for range in &self.ranges {
+            let source_end = range.source_start + range.length;
+            if start >= interval.end {
+                break;
+            }
+            if range.length == 0 || source_end <= start {
+                continue;
+            }
+
+            if range.source_start > start {
+                let end = range.source_start.min(interval.end);
+                mapped.push(start..end);
+                start = end;
+                if start == interval.end {
+                    break;
+                }
+            }
+
+            let end = source_end.min(interval.end);
+            let offset = start - range.source_start;
+            mapped.push(range.destination_start + offset..range.destination_start + offset + (end - start));
+            start = end;
+        }
+
+        if start < interval.end {
+            mapped.push(start..interval.end);
+        }
+
+        mapped
+    }
+
+    /// Composes this map with `next` into a single map that maps like `self` followed by `next`.
+    /// Only the pieces that do not map to themselves are kept, merged where they line up.
+    fn then(&self, next: &RangeCollection) -> RangeCollection {
+        // This map as pieces covering every number, each with the start of its destination.
+        let mut pieces = vec![];
+        let mut start = 0;
+        for range in self.ranges.iter().filter(|r| r.length > 0) {
+            pieces.push((start..range.source_start, start));
+            pieces.push((range.source_start..range.source_start + range.length, range.destination_start));
+            start = range.source_start + range.length;
+        }
+        pieces.push((start..usize::MAX, start));
+
+        let mut ranges: Vec<Range> = vec![];
+        for (source, destination_start) in pieces {
+            let mut source_start = source.start;
+            for mapped in next.map_interval(destination_start..destination_start + source.len()) {
+                match ranges.last_mut() {
+                    Some(last) if last.source_start + last.length == source_start
+                        && last.destination_start + last.length == mapped.start => last.length += mapped.len(),
+                    _ if mapped.start == source_start => {}
+                    _ => ranges.push(Range { source_start, destination_start: mapped.start, length: mapped.len() }),
+                }
+                source_start += mapped.len();
+            }
+        }
+
+        RangeCollection { source: self.source.clone(), destination: next.destination.clone(), ranges }
+    }
+}
+
+impl Display for RangeCollection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.ranges.iter().join("\n"))
+    }
+}
+
+impl FromStr for RangeCollection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RangeCollection::parse_at(s, 0)
+    }
+}
+
+#[derive(Debug)]
+struct Range {
+    source_start: usize,
+    destination_start: usize,
+    length: usize,
+}
+
+impl Range {
+    fn map(&self, source: usize) -> Option<usize> {
+        if source >= self.source_start && source < self.source_start + self.length {
+            Some(self.destination_start + (source - self.source_start))
+        } else {
+            None
+        }
+    }
+
+    fn unmap(&self, destination: usize) -> Option<usize> {
+        if destination >= self.destination_start && destination - self.destination_start < self.length {
+            Some(self.source_start + (destination - self.destination_start))
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.destination_start, self.source_start, self.length)
+    }
+}
+
+impl FromStr for Range {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = s.split_whitespace().map(|n| parse_field(s, n)).collect::<Result<Vec<_>>>()?;
+        let [destination_start, source_start, length] = numbers[..] else {
+            return Err(Error::new(format!("expected three numbers, found {}", numbers.len())));
+        };
+        Ok(Range { destination_start, source_start, length })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use crate::Error;
+    use super::{diagnose, Diagnostic, InputContext, part1, part2, part2_per_seed, Problem, Range, RangeCollection};
+
+    const EXAMPLE_INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn test_range() {
+        let range1 = "50 98 2".parse::<Range>().unwrap();
+        assert_eq!(range1.map(97), None);
+        assert_eq!(range1.map(98), Some(50));
+        assert_eq!(range1.map(99), Some(51));
+        assert_eq!(range1.map(100), None);
+
+        let range2 = "52 50 48".parse::<Range>().unwrap();
+        assert_eq!(range2.map(49), None);
+        (50..).take(48).for_each(|i| assert_eq!(range2.map(i), Some(i - 50 + 52)));
+        assert_eq!(range2.map(98), None);
+
+        assert_eq!(range1.unmap(49), None);
+        assert_eq!(range1.unmap(50), Some(98));
+        assert_eq!(range1.unmap(51), Some(99));
+        assert_eq!(range1.unmap(52), None);
+        (50..150).for_each(|i| assert_eq!(range2.map(i).and_then(|d| range2.unmap(d)), range2.map(i).map(|_| i)));
+    }
+
+    #[test]
+    fn test_range_collection() {
+        let input = "seed-to-soil map:
+50 98 2
+52 50 48";
+        let range_collection = input.parse::<RangeCollection>().unwrap();
+        assert_eq!(range_collection.map(0), 0);
+        assert_eq!(range_collection.map(1), 1);
+        assert_eq!(range_collection.map(13), 13);
+        assert_eq!(range_collection.map(14), 14);
+        assert_eq!(range_collection.map(48), 48);
+        assert_eq!(range_collection.map(49), 49);
+        assert_eq!(range_collection.map(50), 52);
+        assert_eq!(range_collection.map(51), 53);
+        assert_eq!(range_collection.map(55), 57);
+        assert_eq!(range_collection.map(79), 81);
+        assert_eq!(range_collection.map(96), 98);
+        assert_eq!(range_collection.map(97), 99);
+        assert_eq!(range_collection.map(98), 50);
+        assert_eq!(range_collection.map(99), 51);
+
+        assert_eq!(range_collection.unmap(10), [10]);
+        assert_eq!(range_collection.unmap(50), [98]);
+        assert_eq!(range_collection.unmap(52), [50]);
+        assert_eq!(range_collection.unmap(98), [96]);
+        assert_eq!(range_collection.unmap(99), [97]);
+        assert_eq!("a-to-b map:\n10 0 5".parse::<RangeCollection>().unwrap().unmap(12), [2, 12]);
+        assert_eq!(range_collection.unmap(100), [100]);
+        for destination in 0..110 {
+            let sources = (0..110).filter(|s| range_collection.map(*s) == destination).collect::<Vec<_>>();
+            assert_eq!(range_collection.unmap(destination), sources);
+        }
+    }
+
+    #[test]
+    fn test_map_interval() {
+        let range_collection = "seed-to-soil map:\n50 98 2\n52 50 48".parse::<RangeCollection>().unwrap();
+        let map = |interval| range_collection.map_interval(interval).into_iter().map(|i| (i.start, i.end)).collect_vec();
+
+        assert_eq!(map(0..10), [(0, 10)]);
+        assert_eq!(map(45..55), [(45, 50), (52, 57)]);
+        assert_eq!(map(79..93), [(81, 95)]);
+        assert_eq!(map(90..110), [(92, 100), (50, 52), (100, 110)]);
+        assert_eq!(map(98..99), [(50, 51)]);
+        assert_eq!(map(100..120), [(100, 120)]);
+        assert!(map(60..60).is_empty());
+
+        // Every number of the interval must map exactly like it does on its own.
+        for (start, end) in [(0, 120), (49, 51), (97, 101)] {
+            let mapped = range_collection.map_interval(start..end).into_iter().flatten().collect::<Vec<_>>();
+            assert_eq!(mapped, (start..end).map(|s| range_collection.map(s)).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_then() {
+        let first = "a-to-b map:\n50 98 2\n52 50 48".parse::<RangeCollection>().unwrap();
+        let second = "b-to-c map:\n0 15 37\n37 52 2\n39 0 15".parse::<RangeCollection>().unwrap();
+        let composed = first.then(&second);
+
+        assert_eq!(composed.to_string(), "39 0 15\n0 15 35\n37 50 2\n54 52 46\n35 98 2");
+        (0..120).for_each(|s| assert_eq!(composed.map(s), second.map(first.map(s))));
+
+        let identity = RangeCollection::default();
+        assert_eq!(identity.then(&first).to_string(), first.to_string());
+        assert_eq!(first.then(&identity).to_string(), first.to_string());
+    }
+
+    #[test]
+    fn test_location_map() {
+        let input = EXAMPLE_INPUT.parse::<InputContext>().unwrap();
+        (0..120).for_each(|s| assert_eq!(input.location_map.map(s), input.map_to_location(s)));
+        assert_eq!(input.location_map().to_string().lines().count(), input.location_map.ranges.len());
+    }
+
+    #[test]
+    fn test_identity_location_map() {
+        let input = "seeds: 1 2\n\nseed-to-location map:\n5 5 10".parse::<InputContext>().unwrap();
+        assert_eq!(input.location_map().to_string(), "");
+        assert_eq!(input.location_map.map(7), 7);
+        assert_eq!(part1(&input), 1);
+        assert_eq!(part2(&input), 1);
+    }
+
+    #[test]
+    fn test_convert() {
+        let input = EXAMPLE_INPUT.parse::<InputContext>().unwrap();
+        assert_eq!(input.location_map.source, "seed");
+        assert_eq!(input.location_map.destination, "location");
+
+        assert_eq!(input.convert("seed", "humidity", 79), Ok(78));
+        assert_eq!(input.convert("soil", "water", 81), Ok(81));
+        assert_eq!(input.convert("light", "light", 5), Ok(5));
+        assert_eq!(input.trace("seed", "light", 74), Ok(vec![79]));
+        assert_eq!(input.convert("location", "seed", 82), Err(Error::new("no path of maps from location to seed")));
+        assert_eq!(input.convert("seed", "gold", 82), Err(Error::new("no path of maps from seed to gold")));
+    }
+
+    #[test]
+    fn test_shuffled_maps() {
+        let (seeds, maps) = EXAMPLE_INPUT.split_once("\n\n").unwrap();
+        let shuffled = format!("{seeds}\n\n{}", maps.split("\n\n").collect_vec().into_iter().rev().join("\n\n"));
+        let input = shuffled.parse::<InputContext>().unwrap();
+
+        assert_eq!(part1(&input), 35);
+        assert_eq!(part2(&input), 46);
+    }
+
+    #[test]
+    fn test_graph_errors() {
+        let missing = EXAMPLE_INPUT.replace("water-to-light", "water-to-lamp");
+        assert_eq!(missing.parse::<InputContext>().err(), Some(Error::new("no path of maps from seed to location")));
+
+        let ambiguous = format!("{EXAMPLE_INPUT}\n\nsoil-to-light map:\n0 0 1");
+        assert_eq!(ambiguous.parse::<InputContext>().err(), Some(Error::new("2 paths of maps from seed to location, expected one")));
+
+        // A cycle back to an earlier category is not another path.
+        let cycle = format!("{EXAMPLE_INPUT}\n\nwater-to-seed map:\n0 0 1");
+        assert_eq!(cycle.parse::<InputContext>().map(|input| part1(&input)), Ok(35));
+    }
+
+    #[test]
+    fn test_input_context() {
+        let input = EXAMPLE_INPUT.parse::<InputContext>().unwrap();
+        assert_eq!(input.map_to_location(79), 82);
+        assert_eq!(input.map_to_location(14), 43);
+        assert_eq!(input.map_to_location(55), 86);
+        assert_eq!(input.map_to_location(13), 35);
+    }
+
+    #[test]
+    fn test_trace_location() {
+        let input = EXAMPLE_INPUT.parse::<InputContext>().unwrap();
+        assert_eq!(input.trace_location(82), [79]);
+        assert_eq!(input.trace_location(35), [13]);
+        for location in 0..120 {
+            assert!(input.trace_location(location).iter().all(|s| input.map_to_location(*s) == location));
+        }
+
+        // Searching locations upwards for the first one that comes from a seed gives part 2.
+        let is_seed = |s: &usize| input.seed_intervals().any(|i| i.contains(s));
+        assert_eq!((0..).find(|l| input.trace_location(*l).iter().any(is_seed)), Some(part2(&input)));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = EXAMPLE_INPUT.parse::<InputContext>().unwrap();
+        assert_eq!(part1(&input), 35);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = EXAMPLE_INPUT.parse::<InputContext>().unwrap();
+        assert_eq!(part2(&input), 46);
+        assert_eq!(part2_per_seed(&input), 46);
+    }
+
+    #[test]
+    fn test_part2_matches_per_seed() {
+        // A small linear congruential generator, to get varied but reproducible almanacs.
+        let mut state = 12345u64;
+        let mut next = |bound: u64| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) % bound
+        };
+
+        for _ in 0..50 {
+            let seeds = (0..4).map(|_| format!("{} {}", next(200), next(50))).join(" ");
+            let maps = (0..3)
+                .map(|m| {
+                    // Consecutive source ranges with gaps so they never overlap.
+                    let mut source = next(20);
+                    let ranges = (0..next(4) + 1).map(|_| {
+                        let length = next(30) + 1;
+                        let range = format!("{} {source} {length}", next(250));
+                        source += length + next(10);
+                        range
+                    }).join("\n");
+                    let categories = ["seed", "soil", "water", "location"];
+                    format!("{}-to-{} map:\n{ranges}", categories[m], categories[m + 1])
+                })
+                .join("\n\n");
+
+            let input = format!("seeds: {seeds}\n\n{maps}").parse::<InputContext>().unwrap();
+            assert_eq!(part2(&input), part2_per_seed(&input));
+        }
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let input = "seeds: 1 2
+
+seed-to-soil map:
+10 0 5
+20 3 4
+30 20 0
+
+soil-to-location map:
+0 50 10
+5 40 30
+0 45 2
+18446744073709551610 0 10";
+
+        assert_eq!(diagnose(input), Ok(vec![
+            Diagnostic { line: 5, problem: Problem::Overlap { line: 4 } },
+            Diagnostic { line: 6, problem: Problem::ZeroLength },
+            Diagnostic { line: 9, problem: Problem::Overlap { line: 10 } },
+            Diagnostic { line: 11, problem: Problem::Overlap { line: 10 } },
+            Diagnostic { line: 12, problem: Problem::Overflow },
+        ]));
+        assert_eq!(diagnose(EXAMPLE_INPUT), Ok(vec![]));
+        assert_eq!(diagnose("seeds: 1\n\nseed-to-soil map:\n1 2"), Err(Error::new("expected three numbers, found 2").with_line(4)));
+
+        assert_eq!(Diagnostic { line: 5, problem: Problem::Overlap { line: 4 } }.to_string(), "line 5: source range overlaps the one on line 4");
+        assert_eq!(input.parse::<InputContext>().err(), Some(Error::new("source range overlaps the one on line 4 (and 4 more problems)").with_line(5)));
+
+        // Ranges that only touch do not overlap.
+        assert_eq!(diagnose("seeds: 1\n\nseed-to-soil map:\n0 0 5\n10 5 5"), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("50 98".parse::<Range>().err(), Some(Error::new("expected three numbers, found 2")));
+        assert_eq!("50 x 2".parse::<Range>().err(), Some(Error::new("invalid number 'x'").with_column(4)));
+
+        let input = EXAMPLE_INPUT.replace("37 52 2", "37 52 -2");
+        let error = input.parse::<InputContext>().err().unwrap();
+        assert_eq!(error.to_string(), "line 9, col 7: invalid number '-2'");
+
+        let input = EXAMPLE_INPUT.replace("soil-to-fertilizer map:", "soil to fertilizer");
+        let error = input.parse::<InputContext>().err().unwrap();
+        assert_eq!(error.to_string(), "line 7: expected '<source>-to-<destination> map:', found 'soil to fertilizer'");
+
+        let input = EXAMPLE_INPUT.replace("seeds: 79 14 55 13", "seeds:");
+        let error = input.parse::<InputContext>().err().unwrap();
+        assert_eq!(error.to_string(), "line 1: expected pairs of seed starts and lengths, found 0 numbers");
+
+        let input = EXAMPLE_INPUT.replace("seeds: 79 14 55 13", "seeds: 79 14 55");
+        let error = input.parse::<InputContext>().err().unwrap();
+        assert_eq!(error.to_string(), "line 1: expected pairs of seed starts and lengths, found 3 numbers");
+    }
+}
\ No newline at end of file
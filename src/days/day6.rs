@@ -0,0 +1,396 @@
+use std::str::FromStr;
+use num::{BigUint, Integer, ToPrimitive};
+use num::integer::Roots;
+use crate::{Error, parse_field, Result, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Input = Races;
+    type Output1 = BigUint;
+    type Output2 = BigUint;
+
+    fn parse(input: &str) -> Result<Races> { input.parse() }
+
+    fn part1(input: &Races) -> BigUint { part1(input) }
+
+    fn part2(input: &Races) -> BigUint { part2(input) }
+}
+
+fn part1(input: &Races) -> BigUint {
+    input.races.iter().map(Race::win_count).product()
+}
+
+fn part2(input: &Races) -> BigUint {
+    let race = &input.combined;
+
+    match (race.time.to_u128(), race.distance.to_u128()) {
+        (Some(time), Some(distance)) => Race { time, distance }.win_count(),
+        _ => race.win_condition_count(),
+    }
+}
+
+/// A race, exact for any times whose square fits in `T`.
+#[derive(Debug, Eq, PartialEq)]
+struct Race<T = u128> {
+    time: T,
+    distance: T,
+}
+
+impl<T: Integer + Roots + Clone + From<u8>> Race<T> {
+    /// The shortest and longest winning hold times, if any. Holding for `h` travels `h * (time - h)`,
+    /// which beats the record when `h² - time * h + distance + 1 <= 0`, so between the roots
+    /// `(time ± √(time² - 4 * (distance + 1))) / 2`. Rounding the integer square root down and
+    /// halving gives the longest hold exactly; the shortest mirrors it around `time / 2`.
+    fn win_hold_interval(&self) -> Option<(T, T)> {
+        let square = self.time.clone() * self.time.clone();
+        let needed = (self.distance.clone() + T::one()) * T::from(4);
+        if square < needed {
+            return None;
+        }
+
+        let upper = (self.time.clone() + (square - needed).sqrt()) / T::from(2);
+        let lower = self.time.clone() - upper.clone();
+
+        (lower <= upper).then_some((lower, upper))
+    }
+
+    fn win_condition_count(&self) -> T {
+        self.win_hold_interval().map_or(T::zero(), |(lower, upper)| upper - lower + T::one())
+    }
+}
+
+impl Race {
+    /// The number of winning hold times, on u128 when `time²` and `4 * (distance + 1)` fit and
+    /// with big integers otherwise.
+    fn win_count(&self) -> BigUint {
+        let fits = self.time.checked_mul(self.time).is_some()
+            && self.distance.checked_add(1).and_then(|d| d.checked_mul(4)).is_some();
+
+        if fits {
+            self.win_condition_count().into()
+        } else {
+            Race { time: BigUint::from(self.time), distance: BigUint::from(self.distance) }.win_condition_count()
+        }
+    }
+}
+
+/// How far a boat gets in a race depending on how long its button is held.
+pub trait BoatModel {
+    /// The distance covered in a race of `time` milliseconds after holding the button for `hold`.
+    /// It must strictly increase up to its peak and never increase after it.
+    fn distance(&self, hold: u128, time: u128) -> u128;
+
+    /// The number of hold times that beat `record`.
+    fn win_count(&self, time: u128, record: u128) -> u128 {
+        count_wins(self, time, record)
+    }
+}
+
+/// The boat of the puzzle: every millisecond held adds one millimetre per millisecond of speed.
+pub struct Standard;
+
+impl BoatModel for Standard {
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        hold.saturating_mul(time.saturating_sub(hold))
+    }
+
+    fn win_count(&self, time: u128, record: u128) -> u128 {
+        // Neither holding for 0 nor for the whole race moves the boat, so the count is below `time`.
+        Race { time, distance: record }.win_count().to_u128().unwrap()
+    }
+}
+
+/// A boat that already has some speed before the button is held at all.
+pub struct LinearBoost {
+    pub initial_speed: u128,
+}
+
+impl BoatModel for LinearBoost {
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        self.initial_speed.saturating_add(hold).saturating_mul(time.saturating_sub(hold))
+    }
+}
+
+/// A boat that stops gaining speed at some point.
+pub struct CappedSpeed {
+    pub max_speed: u128,
+}
+
+impl BoatModel for CappedSpeed {
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        hold.min(self.max_speed).saturating_mul(time.saturating_sub(hold))
+    }
+}
+
+/// A boat that gains `acceleration` millimetres per millisecond of speed per millisecond held.
+pub struct Acceleration {
+    pub acceleration: u128,
+}
+
+impl BoatModel for Acceleration {
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        self.acceleration.saturating_mul(hold).saturating_mul(time.saturating_sub(hold))
+    }
+}
+
+/// A boat that needs `penalty` milliseconds after the button is released before it moves.
+pub struct ChargePenalty {
+    pub penalty: u128,
+}
+
+impl BoatModel for ChargePenalty {
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        hold.saturating_mul(time.saturating_sub(hold).saturating_sub(self.penalty))
+    }
+}
+
+/// Counts the hold times that beat `record` for any model by binary searching for the peak
+/// distance, then for the first and last winning hold times on either side of it. Distances
+/// saturate at `u128::MAX`, which still beats any smaller record.
+pub fn count_wins<M: BoatModel + ?Sized>(model: &M, time: u128, record: u128) -> u128 {
+    let distance = |hold| model.distance(hold, time);
+
+    // The first hold time where holding longer stops helping.
+    let peak = partition_point(0, time, |h| distance(h + 1) > distance(h));
+    if distance(peak) <= record {
+        return 0;
+    }
+
+    let first = partition_point(0, peak, |h| distance(h) <= record);
+    // Searching `peak..time` and checking `time` itself separately, as `time + 1` may overflow.
+    let end = partition_point(peak, time, |h| distance(h) > record);
+    let last = if distance(end) > record { end } else { end - 1 };
+
+    last - first + 1
+}
+
+/// The first value in `low..high` for which `predicate` is false, given that it is true for a
+/// prefix of the range and false for the rest, or `high` if it is never false.
+fn partition_point(mut low: u128, mut high: u128, predicate: impl Fn(u128) -> bool) -> u128 {
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if predicate(middle) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+/// The races read both ways: one per column for part 1, and a single race with the digits of
+/// each line joined for part 2.
+pub struct Races {
+    races: Vec<Race>,
+    combined: Race<BigUint>,
+}
+
+impl FromStr for Races {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut times = None;
+        let mut distances = None;
+
+        for (i, line) in s.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            let (label, numbers) = line.split_once(':')
+                .ok_or_else(|| Error::new("expected '<label>: <numbers>'").with_line(i + 1))?;
+
+            let label = label.trim();
+            let field = match label {
+                "Time" => &mut times,
+                "Distance" => &mut distances,
+                _ => return Err(Error::at(line, label, format!("unknown label '{label}', expected Time or Distance")).with_line(i + 1)),
+            };
+            if field.is_some() {
+                return Err(Error::new(format!("duplicate '{label}:' line")).with_line(i + 1));
+            }
+
+            let values = numbers.split_whitespace()
+                .map(|n| parse_field::<u128>(line, n))
+                .collect::<Result<Vec<_>>>()
+                .map_err(|e| e.with_line(i + 1))?;
+            let joined = numbers.split_whitespace().collect::<String>();
+            *field = Some((i + 1, values, joined));
+        }
+
+        let (_, times, time) = times.ok_or_else(|| Error::new("missing 'Time:' line"))?;
+        let (line, distances, distance) = distances.ok_or_else(|| Error::new("missing 'Distance:' line"))?;
+
+        if times.len() != distances.len() {
+            return Err(Error::new(format!("found {} times but {} distances", times.len(), distances.len())).with_line(line));
+        }
+        if times.is_empty() {
+            return Err(Error::new("no races"));
+        }
+
+        let big = |digits: String| digits.parse::<BigUint>().map_err(|e| Error::new(e.to_string()));
+
+        Ok(Races {
+            races: times.into_iter().zip(distances).map(|(time, distance)| Race { time, distance }).collect(),
+            combined: Race { time: big(time)?, distance: big(distance)? },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use crate::Error;
+    use super::{Acceleration, BoatModel, CappedSpeed, ChargePenalty, count_wins, LinearBoost, part1, Race, Races, part2, Standard};
+
+    const EXAMPLE_INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&EXAMPLE_INPUT.parse().unwrap()), BigUint::from(288u32));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&EXAMPLE_INPUT.parse().unwrap()), BigUint::from(71503u32));
+
+        // Too large for a u128 square, so computed with big integers.
+        let input = "Time:      10000000000 00000000000\nDistance:  23999999999999999999 9999999999999999999999";
+        assert_eq!(part2(&input.parse().unwrap()), BigUint::from(200000000000000000001u128));
+    }
+
+    #[test]
+    fn test_parse_races() {
+        let races = EXAMPLE_INPUT.parse::<Races>().unwrap();
+        let expected = [
+            Race { time: 7, distance: 9 },
+            Race { time: 15, distance: 40 },
+            Race { time: 30, distance: 200 },
+        ];
+
+        assert_eq!(races.races, expected);
+        assert_eq!(races.combined, Race { time: 71530u32.into(), distance: 940200u32.into() });
+
+        // Labels are found by name, in any order and with any spacing or blank lines.
+        let races = "\nDistance: 9 40  200\n\n   Time:7\t15 30\n\n".parse::<Races>().unwrap();
+        assert_eq!(races.races, expected);
+        assert_eq!(races.combined, Race { time: 71530u32.into(), distance: 940200u32.into() });
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| input.parse::<Races>().err();
+
+        assert_eq!(error("Time: 7 15\nDistance: 9 40 200"), Some(Error::new("found 2 times but 3 distances").with_line(2)));
+        assert_eq!(error("Time: 7\nSpeed: 9"), Some(Error::new("unknown label 'Speed', expected Time or Distance").with_line(2).with_column(1)));
+        assert_eq!(error("Time: 7\nTime: 8\nDistance: 9"), Some(Error::new("duplicate 'Time:' line").with_line(2)));
+        assert_eq!(error("Time: 7"), Some(Error::new("missing 'Distance:' line")));
+        assert_eq!(error("Distance: 9"), Some(Error::new("missing 'Time:' line")));
+        assert_eq!(error("Time: 7\nDistance: 9 x"), Some(Error::new("invalid number 'x'").with_line(2).with_column(13)));
+        assert_eq!(error("Time 7\nDistance: 9"), Some(Error::new("expected '<label>: <numbers>'").with_line(1)));
+        assert_eq!(error("Time:\nDistance:"), Some(Error::new("no races")));
+    }
+
+    #[test]
+    fn test_win_condition_count() {
+        assert_eq!(Race { time: 7, distance: 9 }.win_condition_count(), 4);
+        assert_eq!(Race { time: 15, distance: 40 }.win_condition_count(), 8);
+        assert_eq!(Race { time: 30, distance: 200 }.win_condition_count(), 9);
+    }
+
+    #[test]
+    fn test_win_hold_interval_matches_brute_force() {
+        for time in 0..60u128 {
+            for distance in 0..=time * time / 4 + 1 {
+                let wins = (0..=time).filter(|h| h * (time - h) > distance).collect::<Vec<_>>();
+                let expected = wins.first().map(|lower| (*lower, *wins.last().unwrap()));
+                assert_eq!(Race { time, distance }.win_hold_interval(), expected, "time {time}, distance {distance}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_boat_models() {
+        let models: [&dyn BoatModel; 8] = [
+            &Standard,
+            &LinearBoost { initial_speed: 0 },
+            &LinearBoost { initial_speed: 7 },
+            &CappedSpeed { max_speed: 4 },
+            &CappedSpeed { max_speed: 100 },
+            &Acceleration { acceleration: 3 },
+            &ChargePenalty { penalty: 5 },
+            &ChargePenalty { penalty: 50 },
+        ];
+
+        for model in models {
+            for time in 0..40 {
+                for record in (0..300).step_by(7) {
+                    let expected = (0..=time).filter(|h| model.distance(*h, time) > record).count() as u128;
+                    assert_eq!(model.win_count(time, record), expected, "time {time}, record {record}");
+                    assert_eq!(count_wins(model, time, record), expected, "time {time}, record {record}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_wins_large() {
+        // The generic solver agrees with the closed form on part 2 sized races.
+        let (time, record) = (71530, 940200);
+        assert_eq!(count_wins(&Standard, time, record), 71503);
+        assert_eq!(count_wins(&Standard, 55_826_490, 246_144_110_121_111), Standard.win_count(55_826_490, 246_144_110_121_111));
+
+        // Capped at 10, holding wins from 6 ms (6 * 194 > 1000) up to 99 ms (10 * 101 > 1000).
+        assert_eq!(count_wins(&CappedSpeed { max_speed: 10 }, 200, 1000), 94);
+    }
+
+    #[test]
+    fn test_count_wins_overflow() {
+        // Every hold but 0 and the whole race beats a record of 5, however far the boat gets.
+        assert_eq!(count_wins(&CappedSpeed { max_speed: 1 << 70 }, 1 << 70, 5), (1 << 70) - 1);
+        assert_eq!(count_wins(&Standard, u128::MAX, 5), u128::MAX - 1);
+        assert_eq!(count_wins(&LinearBoost { initial_speed: u128::MAX }, u128::MAX, 5), u128::MAX);
+        assert_eq!(count_wins(&Acceleration { acceleration: 1 << 100 }, u128::MAX, 5), u128::MAX - 1);
+        assert_eq!(count_wins(&ChargePenalty { penalty: 1 }, u128::MAX, 5), u128::MAX - 2);
+        assert_eq!(count_wins(&Standard, u128::MAX, u128::MAX), 0);
+    }
+
+    #[test]
+    fn test_perfect_square_boundaries() {
+        // With time = a + b and distance + 1 = a * b, the discriminant is (b - a)², so holding
+        // for exactly a or b ties the record plus one and both are the first and last winners.
+        for (a, b) in [(1_000_000_007u128, 3_000_000_019), (4_294_967_291, 4_294_967_311), (1, 1 << 62)] {
+            let race = Race { time: a + b, distance: a * b - 1 };
+            assert_eq!(race.win_hold_interval(), Some((a, b)));
+
+            // One more metre and the boundary holds only tie, so they no longer win.
+            let race = Race { time: a + b, distance: a * b };
+            assert_eq!(race.win_hold_interval(), (a < b - 1).then_some((a + 1, b - 1)));
+        }
+
+        // A discriminant of exactly zero wins only by holding for half the time.
+        assert_eq!(Race { time: 1u128 << 60, distance: (1 << 118) - 1 }.win_condition_count(), 1);
+        assert_eq!(Race { time: 1u128 << 60, distance: 1 << 118 }.win_condition_count(), 0);
+
+        let big = |n: u128| BigUint::from(n);
+        let race = Race { time: big(1 << 100) * big(3), distance: big(1 << 100) * big(1 << 100) * big(2) - big(1) };
+        assert_eq!(race.win_hold_interval(), Some((big(1 << 100), big(1 << 100) * big(2))));
+    }
+
+    #[test]
+    fn test_overflowing_races() {
+        // Times of 2^64 and more square past u128, as do distances past 2^126 once quadrupled.
+        let input = "Time: 20000000000000000000\nDistance: 5";
+        assert_eq!(part1(&input.parse().unwrap()), BigUint::from(19_999_999_999_999_999_999u128));
+        assert_eq!(part2(&input.parse().unwrap()), BigUint::from(19_999_999_999_999_999_999u128));
+        assert_eq!(Standard.win_count(20_000_000_000_000_000_000, 5), 19_999_999_999_999_999_999);
+
+        let input = "Time: 10\nDistance: 100000000000000000000000000000000000000";
+        assert_eq!(part1(&input.parse().unwrap()), BigUint::from(0u32));
+        assert_eq!(part2(&input.parse().unwrap()), BigUint::from(0u32));
+        assert_eq!(Standard.win_count(10, u128::MAX), 0);
+        assert_eq!(Standard.win_count(u128::MAX, 1), u128::MAX - 1);
+    }
+}
\ No newline at end of file
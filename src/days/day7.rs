@@ -0,0 +1,692 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use crate::{column_of, Error, parse_field, Result, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Input> { parse_input(input) }
+
+    fn part1(input: &Input) -> i32 { part1(input) }
+
+    fn part2(input: &Input) -> i32 { part2(input) }
+}
+
+fn part1(input: &Input) -> i32 { solver(input, &RuleSet::standard()) }
+
+fn part2(input: &Input) -> i32 { solver(input, &RuleSet::jokers()) }
+
+/// The total winnings of all hands when ranked by `rules`.
+pub fn solver(input: &Input, rules: &RuleSet) -> i32 {
+    ranking(input, rules).iter().map(|r| r.winnings).sum()
+}
+
+/// All hands ranked by `rules`, weakest first.
+pub fn ranking(input: &Input, rules: &RuleSet) -> Vec<Ranked> {
+    let mut hands = input.hands.iter().collect_vec();
+    hands.sort_by_cached_key(|(h, _)| (rules.hand_type(h), rules.tie_breaker(h)));
+
+    hands.into_iter()
+        .enumerate()
+        .map(|(i, (hand, bid))| {
+            let (hand_type, substitutes) = rules.evaluate(hand);
+            let wild = hand.cards.iter().copied().filter(|c| rules.wild.contains(c));
+            let substitution = wild.zip(substitutes).collect();
+            Ranked { rank: i + 1, hand: hand.clone(), hand_type, substitution, bid: *bid, winnings: (i + 1) as i32 * bid }
+        })
+        .collect()
+}
+
+/// A hand with its place in the ranking.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Ranked {
+    pub rank: usize,
+    pub hand: Hand,
+    pub hand_type: HandType,
+    /// Every wild card of the hand, in the order dealt, with the card it stands in for.
+    pub substitution: Vec<(Card, Card)>,
+    pub bid: i32,
+    pub winnings: i32,
+}
+
+/// Compares two hands under `rules` and tells what decided it.
+pub fn compare_explained(a: &Hand, b: &Hand, rules: &RuleSet) -> Comparison {
+    let (type_a, type_b) = (rules.hand_type(a), rules.hand_type(b));
+    if type_a != type_b {
+        return Comparison { ordering: type_a.cmp(&type_b), reason: Reason::Type(type_a, type_b) };
+    }
+
+    let (cards_a, cards_b) = (rules.tie_cards(a), rules.tie_cards(b));
+    let reason = match cards_a.iter().zip(&cards_b).position(|(x, y)| x != y) {
+        Some(i) => Reason::Card { position: i + 1, a: cards_a[i], b: cards_b[i] },
+        None if cards_a.len() != cards_b.len() => Reason::Length(cards_a.len(), cards_b.len()),
+        None => Reason::Tie,
+    };
+
+    Comparison { ordering: rules.tie_breaker(a).cmp(&rules.tie_breaker(b)), reason }
+}
+
+/// The outcome of [`compare_explained`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Comparison {
+    /// How the first hand ranks against the second.
+    pub ordering: Ordering,
+    pub reason: Reason,
+}
+
+/// What decided a [`Comparison`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Reason {
+    /// The hands are of different types.
+    Type(HandType, HandType),
+    /// The hands are of the same type and the first differing card is at `position`, counting
+    /// from 1 among the cards that break ties.
+    Card { position: usize, a: Card, b: Card },
+    /// The hands are of the same type and agree on every card they share, but one has more cards
+    /// that break ties. Only happens when wild cards are left out of ties.
+    Length(usize, usize),
+    /// Nothing tells the hands apart.
+    Tie,
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sign = match self.ordering {
+            Ordering::Less => '<',
+            Ordering::Equal => '=',
+            Ordering::Greater => '>',
+        };
+
+        match self.reason {
+            Reason::Type(a, b) => write!(f, "{a:?} vs {b:?}"),
+            Reason::Card { position, a, b } =>
+                write!(f, "tie on type, decided at position {position}: {} {sign} {}", char::from(a), char::from(b)),
+            Reason::Length(a, b) => write!(f, "tie on type and cards, decided by cards compared: {a} {sign} {b}"),
+            Reason::Tie => write!(f, "tie on type and every card"),
+        }
+    }
+}
+
+/// How a variant of Camel Cards ranks hands: how many cards a hand holds, the order of the cards,
+/// which of them are wild, whether straights count and whether wild cards count when breaking
+/// ties between hands of the same type.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuleSet {
+    /// The number of cards in every hand.
+    hand_size: usize,
+    /// Every card, weakest first.
+    order: Vec<Card>,
+    /// Cards that stand in for whichever card makes the strongest hand type.
+    wild: Vec<Card>,
+    /// Whether ties are broken on every card in the order dealt, or on all but the wild ones.
+    wild_in_ties: bool,
+    /// Whether a run of five consecutive cards makes a straight.
+    straights: bool,
+}
+
+impl RuleSet {
+    /// The names of the built-in rule sets accepted by [`RuleSet::named`].
+    pub const NAMES: [&'static str; 6] = ["standard", "jokers", "aces-low", "deuces-and-jokers", "jokers-not-in-ties", "poker"];
+
+    /// The rules of part 1.
+    pub fn standard() -> RuleSet {
+        RuleSet { hand_size: 5, order: CARDS.to_vec(), wild: vec![], wild_in_ties: true, straights: false }
+    }
+
+    /// The rules of part 2: jacks are jokers, wild but the weakest card on their own.
+    pub fn jokers() -> RuleSet {
+        RuleSet { order: lowest_first(&[Card::J]), wild: vec![Card::J], ..RuleSet::standard() }
+    }
+
+    /// The rules of part 1 with aces as the weakest card.
+    pub fn aces_low() -> RuleSet {
+        RuleSet { order: lowest_first(&[Card::A]), ..RuleSet::standard() }
+    }
+
+    /// Jokers, with twos wild too.
+    pub fn deuces_and_jokers() -> RuleSet {
+        RuleSet { wild: vec![Card::C2, Card::J], ..RuleSet::jokers() }
+    }
+
+    /// Jokers that are left out when breaking ties, so only the natural cards are compared.
+    pub fn jokers_not_in_ties() -> RuleSet {
+        RuleSet { wild_in_ties: false, ..RuleSet::jokers() }
+    }
+
+    /// The rules of part 1 with straights, and flushes for hands with suits.
+    pub fn poker() -> RuleSet {
+        RuleSet { straights: true, ..RuleSet::standard() }
+    }
+
+    /// The same rules for hands of `hand_size` cards.
+    pub fn with_hand_size(self, hand_size: usize) -> RuleSet {
+        RuleSet { hand_size, ..self }
+    }
+
+    /// The same rules with the cards ordered as `order`, weakest first, which must hold every card
+    /// exactly once.
+    pub fn with_order(self, order: &[Card]) -> Result<RuleSet> {
+        if order.len() != CARDS.len() || !CARDS.iter().all(|c| order.contains(c)) {
+            return Err(Error::new("the card order must hold every card exactly once"));
+        }
+        Ok(RuleSet { order: order.to_vec(), ..self })
+    }
+
+    /// The same rules with `wild` as the wild cards.
+    pub fn with_wild(self, wild: &[Card]) -> RuleSet {
+        RuleSet { wild: wild.to_vec(), ..self }
+    }
+
+    /// The same rules with wild cards taking part in breaking ties or not.
+    pub fn with_wild_in_ties(self, wild_in_ties: bool) -> RuleSet {
+        RuleSet { wild_in_ties, ..self }
+    }
+
+    /// The same rules with straights counting or not.
+    pub fn with_straights(self, straights: bool) -> RuleSet {
+        RuleSet { straights, ..self }
+    }
+
+    /// Looks up a built-in rule set by name.
+    pub fn named(name: &str) -> Result<RuleSet> {
+        Ok(match name {
+            "standard" => RuleSet::standard(),
+            "jokers" => RuleSet::jokers(),
+            "aces-low" => RuleSet::aces_low(),
+            "deuces-and-jokers" => RuleSet::deuces_and_jokers(),
+            "jokers-not-in-ties" => RuleSet::jokers_not_in_ties(),
+            "poker" => RuleSet::poker(),
+            _ => return Err(Error::new(format!("unknown rule set '{name}', expected one of {}", RuleSet::NAMES.join(", ")))),
+        })
+    }
+
+    fn rank(&self, card: Card) -> usize {
+        self.order.iter().position(|c| *c == card).unwrap()
+    }
+
+    fn hand_type(&self, hand: &Hand) -> HandType { self.evaluate(hand).0 }
+
+    /// The best type of `hand` and the cards its wild cards stand in for to make it.
+    fn evaluate(&self, hand: &Hand) -> (HandType, Vec<Card>) {
+        let (wild, natural): (Vec<_>, Vec<_>) = hand.cards.iter().partition(|c| self.wild.contains(c));
+        let counts: HashMap<&Card, usize> = natural.iter().copied().counts();
+        let most_common = counts.iter()
+            .max_by_key(|(c, n)| (**n, self.rank(***c)))
+            .map_or(self.order[self.order.len() - 1], |(c, _)| **c);
+        let of_a_kind = (compute_hand_type(counts, wild.len()), vec![most_common; wild.len()]);
+
+        let straight = self.straights.then(|| self.straight(&natural, wild.len())).flatten();
+        let flush = hand.cards.len() >= SEQUENCE_LENGTH && hand.suits.as_ref().is_some_and(|s| s.iter().all_equal());
+
+        let sequence = match (straight, flush) {
+            (Some(s), true) => (HandType::StraightFlush, s),
+            (Some(s), false) => (HandType::Straight, s),
+            (None, true) => (HandType::Flush, of_a_kind.1.clone()),
+            (None, false) => return of_a_kind,
+        };
+        if sequence.0 > of_a_kind.0 { sequence } else { of_a_kind }
+    }
+
+    /// The cards `jokers` wild cards stand in for to complete the strongest run of five
+    /// consecutive cards of the order with `natural`, if there is one. An ace at the top of the
+    /// order also counts below the weakest card, as in A2345.
+    fn straight(&self, natural: &[&Card], jokers: usize) -> Option<Vec<Card>> {
+        let run = self.order.last().filter(|c| **c == Card::A).into_iter().chain(&self.order).copied().collect_vec();
+        let present = natural.iter().copied().collect::<HashSet<_>>();
+
+        run.windows(SEQUENCE_LENGTH).rev().find_map(|window| {
+            let mut missing = window.iter().copied().filter(|c| !present.contains(c)).collect_vec();
+            (missing.len() <= jokers).then(|| {
+                missing.resize(jokers, window[SEQUENCE_LENGTH - 1]);
+                missing
+            })
+        })
+    }
+
+    /// The cards of `hand` that break ties, in the order dealt.
+    fn tie_cards(&self, hand: &Hand) -> Vec<Card> {
+        hand.cards.iter()
+            .copied()
+            .filter(|c| self.wild_in_ties || !self.wild.contains(c))
+            .collect()
+    }
+
+    fn tie_breaker(&self, hand: &Hand) -> Vec<usize> {
+        self.tie_cards(hand).into_iter().map(|c| self.rank(c)).collect()
+    }
+}
+
+/// The number of cards in a straight, and the fewest cards a hand needs to be a flush.
+const SEQUENCE_LENGTH: usize = 5;
+
+/// Every card in the order of part 1.
+const CARDS: [Card; 13] = [
+    Card::C2, Card::C3, Card::C4, Card::C5, Card::C6, Card::C7, Card::C8, Card::C9, Card::T, Card::J, Card::Q, Card::K, Card::A,
+];
+
+/// The order of part 1 with `cards` moved to the bottom.
+fn lowest_first(cards: &[Card]) -> Vec<Card> {
+    cards.iter().copied().chain(CARDS.into_iter().filter(|c| !cards.contains(c))).collect()
+}
+
+fn parse_input(input: &str) -> Result<Input> { parse_hands(input, &RuleSet::standard()) }
+
+/// Parses one hand and bid per line, each hand holding as many cards as `rules` deal.
+pub fn parse_hands(input: &str, rules: &RuleSet) -> Result<Input> {
+    let hands = input.lines()
+        .enumerate()
+        .map(|(i, l)| parse_hand(l, rules.hand_size).map_err(|e| e.with_line(i + 1)))
+        .collect::<Result<_>>()?;
+
+    Ok(Input { hands })
+}
+
+fn parse_hand(line: &str, hand_size: usize) -> Result<(Hand, i32)> {
+    let (hand, bid) = line.split_whitespace().next_tuple()
+        .ok_or_else(|| Error::new("expected a hand and a bid"))?;
+
+    let mut cards = vec![];
+    let mut suits = vec![];
+    let mut chars = hand.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        cards.push(Card::try_from(c).map_err(|e| e.with_column(column_of(line, hand) + i))?);
+        if let Some((_, s)) = chars.next_if(|(_, s)| Suit::try_from(*s).is_ok()) {
+            suits.push(Suit::try_from(s)?);
+        }
+    }
+
+    if cards.len() != hand_size {
+        return Err(Error::at(line, hand, format!("expected {hand_size} cards, found {}", cards.len())));
+    }
+    let suits = match suits.len() {
+        0 => None,
+        n if n == cards.len() => Some(suits),
+        _ => return Err(Error::at(line, hand, "expected a suit on every card or on none")),
+    };
+
+    Ok((Hand { cards, suits }, parse_field(line, bid)?))
+}
+
+/// The cards of a hand in the order dealt, and their suits if the input gives them.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Hand {
+    cards: Vec<Card>,
+    suits: Option<Vec<Suit>>,
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.suits {
+            Some(suits) => self.cards.iter().zip(suits).try_for_each(|(c, s)| write!(f, "{}{}", char::from(*c), char::from(*s))),
+            None => self.cards.iter().try_for_each(|c| write!(f, "{}", char::from(*c))),
+        }
+    }
+}
+
+pub struct Input {
+    hands: Vec<(Hand, i32)>,
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+pub enum Card {
+    C2,
+    C3,
+    C4,
+    C5,
+    C6,
+    C7,
+    C8,
+    C9,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+impl From<Card> for char {
+    fn from(value: Card) -> Self {
+        match value {
+            Card::C2 => '2',
+            Card::C3 => '3',
+            Card::C4 => '4',
+            Card::C5 => '5',
+            Card::C6 => '6',
+            Card::C7 => '7',
+            Card::C8 => '8',
+            Card::C9 => '9',
+            Card::T => 'T',
+            Card::J => 'J',
+            Card::Q => 'Q',
+            Card::K => 'K',
+            Card::A => 'A',
+        }
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '2' => Card::C2,
+            '3' => Card::C3,
+            '4' => Card::C4,
+            '5' => Card::C5,
+            '6' => Card::C6,
+            '7' => Card::C7,
+            '8' => Card::C8,
+            '9' => Card::C9,
+            'T' => Card::T,
+            'J' => Card::J,
+            'Q' => Card::Q,
+            'K' => Card::K,
+            'A' => Card::A,
+            _ => return Err(Error::new(format!("unknown card '{value}'"))),
+        })
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+impl From<Suit> for char {
+    fn from(value: Suit) -> Self {
+        match value {
+            Suit::Clubs => 'c',
+            Suit::Diamonds => 'd',
+            Suit::Hearts => 'h',
+            Suit::Spades => 's',
+        }
+    }
+}
+
+impl TryFrom<char> for Suit {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'c' => Suit::Clubs,
+            'd' => Suit::Diamonds,
+            'h' => Suit::Hearts,
+            's' => Suit::Spades,
+            _ => return Err(Error::new(format!("unknown suit '{value}'"))),
+        })
+    }
+}
+
+/// The types of hands from weakest to strongest. Straights and flushes only occur under rules
+/// that have them, and otherwise these are the Camel Cards types in their usual order.
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    FiveOfAKind,
+}
+
+fn compute_hand_type(counts: HashMap<&Card, usize>, jokers: usize) -> HandType {
+    let counts = counts.into_values().sorted().rev().collect_vec();
+
+    let first_max = counts.first().cloned().unwrap_or(0);
+    let second_max = counts.get(1).cloned().unwrap_or(0);
+
+    if first_max + jokers >= 5 {
+        HandType::FiveOfAKind
+    } else if first_max + jokers >= 4 {
+        HandType::FourOfAKind
+    } else if first_max + jokers >= 3 {
+        let remaining_jokers = jokers - (3 - first_max);
+        if second_max + remaining_jokers >= 2 {
+            HandType::FullHouse
+        } else {
+            HandType::ThreeOfAKind
+        }
+    } else if first_max + jokers >= 2 {
+        let remaining_jokers = jokers - (2 - first_max);
+        if second_max + remaining_jokers >= 2 {
+            HandType::TwoPair
+        } else {
+            HandType::OnePair
+        }
+    } else {
+        HandType::HighCard
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Card::*;
+    use crate::Error;
+    use std::cmp::Ordering;
+    use super::{Card, compare_explained, Hand, HandType, parse_hands, parse_input, part1, part2, ranking, Reason, RuleSet, solver};
+
+    const EXAMPLE_INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    fn hand(cards: &[Card]) -> Hand { Hand { cards: cards.to_vec(), suits: None } }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(part1(&input), 6440);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(part2(&input), 5905);
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = parse_input(EXAMPLE_INPUT).unwrap();
+
+        let expected = [
+            (hand(&[C3, C2, T, C3, K]), 765),
+            (hand(&[T, C5, C5, J, C5]), 684),
+            (hand(&[K, K, C6, C7, C7]), 28),
+            (hand(&[K, T, J, J, T]), 220),
+            (hand(&[Q, Q, Q, J, A]), 483),
+        ];
+
+        assert_eq!(input.hands, expected);
+    }
+
+    #[test]
+    fn test_hand_type1() {
+        assert_eq!(RuleSet::standard().hand_type(&hand(&[A, A, A, A, A])), HandType::FiveOfAKind);
+        assert_eq!(RuleSet::standard().hand_type(&hand(&[A, A, C8, A, A])), HandType::FourOfAKind);
+        assert_eq!(RuleSet::standard().hand_type(&hand(&[C2, C3, C3, C3, C2])), HandType::FullHouse);
+        assert_eq!(RuleSet::standard().hand_type(&hand(&[T, T, T, C9, C8])), HandType::ThreeOfAKind);
+        assert_eq!(RuleSet::standard().hand_type(&hand(&[C2, C3, C4, C3, C2])), HandType::TwoPair);
+        assert_eq!(RuleSet::standard().hand_type(&hand(&[A, C2, C3, A, C4])), HandType::OnePair);
+        assert_eq!(RuleSet::standard().hand_type(&hand(&[C2, C3, C4, C5, C6])), HandType::HighCard);
+    }
+
+    #[test]
+    fn test_hand_type2() {
+        assert_eq!(RuleSet::jokers().hand_type(&hand(&[A, A, A, A, A])), HandType::FiveOfAKind);
+        assert_eq!(RuleSet::jokers().hand_type(&hand(&[J, J, J, J, J])), HandType::FiveOfAKind);
+        assert_eq!(RuleSet::jokers().hand_type(&hand(&[C3, C2, T, C3, K])), HandType::OnePair);
+        assert_eq!(RuleSet::jokers().hand_type(&hand(&[T, C5, C5, J, C5])), HandType::FourOfAKind);
+        assert_eq!(RuleSet::jokers().hand_type(&hand(&[K, K, C6, C7, C7])), HandType::TwoPair);
+        assert_eq!(RuleSet::jokers().hand_type(&hand(&[K, T, J, J, T])), HandType::FourOfAKind);
+        assert_eq!(RuleSet::jokers().hand_type(&hand(&[Q, Q, Q, J, A])), HandType::FourOfAKind);
+    }
+
+    #[test]
+    fn test_rule_sets() {
+        let input = parse_input(EXAMPLE_INPUT).unwrap();
+        let winnings = |name| solver(&input, &RuleSet::named(name).unwrap());
+
+        assert_eq!(winnings("standard"), 6440);
+        assert_eq!(winnings("jokers"), 5905);
+        assert_eq!(winnings("aces-low"), 6440);
+        assert_eq!(winnings("deuces-and-jokers"), 6642);
+        assert_eq!(winnings("jokers-not-in-ties"), 5905);
+        assert_eq!(winnings("poker"), 6440);
+        assert!(RuleSet::named("bridge").is_err());
+        RuleSet::NAMES.iter().for_each(|name| assert!(RuleSet::named(name).is_ok()));
+    }
+
+    #[test]
+    fn test_rule_set_variants() {
+        let rank = |rules: &RuleSet, hands: &str| {
+            let input = parse_input(hands).unwrap();
+            let mut order = input.hands.iter().map(|(_, bid)| *bid).collect::<Vec<_>>();
+            order.sort_by_key(|bid| {
+                let hand = &input.hands[(*bid - 1) as usize].0;
+                (rules.hand_type(hand), rules.tie_breaker(hand))
+            });
+            order
+        };
+
+        // Aces below twos decide the tie the other way.
+        let hands = "A2345 1\n23456 2";
+        assert_eq!(rank(&RuleSet::standard(), hands), [2, 1]);
+        assert_eq!(rank(&RuleSet::aces_low(), hands), [1, 2]);
+
+        // With twos wild as well, 2J2K4 is four of a kind.
+        assert_eq!(RuleSet::deuces_and_jokers().hand_type(&hand(&[C2, J, C2, K, C4])), HandType::FourOfAKind);
+        assert_eq!(RuleSet::jokers().hand_type(&hand(&[C2, J, C2, K, C4])), HandType::ThreeOfAKind);
+
+        // Ignoring jokers in ties compares the natural cards only, so the queen beats the ten.
+        let hands = "JQ234 1\nT2J34 2";
+        assert_eq!(rank(&RuleSet::jokers(), hands), [1, 2]);
+        assert_eq!(rank(&RuleSet::jokers_not_in_ties(), hands), [2, 1]);
+    }
+
+    #[test]
+    fn test_poker_hand_types() {
+        let hand_type = |rules: &RuleSet, cards: &str| {
+            let input = parse_hands(&format!("{cards} 1"), rules).unwrap();
+            rules.hand_type(&input.hands[0].0)
+        };
+        let poker = RuleSet::poker();
+        let wild_poker = RuleSet { wild: vec![C2], ..RuleSet::poker() };
+
+        assert_eq!(hand_type(&poker, "T9Q8J"), HandType::Straight);
+        assert_eq!(hand_type(&RuleSet::standard(), "T9Q8J"), HandType::HighCard);
+        assert_eq!(hand_type(&poker, "T9Q82"), HandType::HighCard);
+        assert_eq!(hand_type(&wild_poker, "T9Q82"), HandType::Straight);
+        assert_eq!(hand_type(&wild_poker, "2J2J3"), HandType::FourOfAKind);
+        assert_eq!(hand_type(&poker, "2h7h9hKh3h"), HandType::Flush);
+        assert_eq!(hand_type(&poker, "2h7h9hKh3s"), HandType::HighCard);
+        assert_eq!(hand_type(&poker, "TsJsQsKsAs"), HandType::StraightFlush);
+        assert_eq!(hand_type(&poker, "2h2s2d9c9h"), HandType::FullHouse);
+        assert_eq!(hand_type(&RuleSet::jokers(), "2h2s2d9cJh"), HandType::FourOfAKind);
+
+        let seven = RuleSet::poker().with_hand_size(7);
+        assert_eq!(hand_type(&seven, "2KQJ7TA"), HandType::Straight);
+        assert_eq!(hand_type(&seven, "2KKJ7TK"), HandType::ThreeOfAKind);
+        let three = RuleSet::poker().with_hand_size(3);
+        assert_eq!(hand_type(&three, "234"), HandType::HighCard);
+        assert_eq!(hand_type(&three, "2h3h4h"), HandType::HighCard);
+        assert_eq!(hand_type(&three, "AAA"), HandType::ThreeOfAKind);
+        assert_eq!(hand_type(&RuleSet::poker().with_hand_size(1), "A"), HandType::HighCard);
+        assert_eq!(hand_type(&RuleSet::poker().with_hand_size(2), "KA"), HandType::HighCard);
+
+        // The ace counts low as well as high, but a straight does not wrap around it.
+        assert_eq!(hand_type(&poker, "A2345"), HandType::Straight);
+        assert_eq!(hand_type(&poker, "3A524"), HandType::Straight);
+        assert_eq!(hand_type(&poker, "JQKA2"), HandType::HighCard);
+        assert_eq!(hand_type(&poker, "As2s3s4s5s"), HandType::StraightFlush);
+        assert_eq!(hand_type(&wild_poker, "A3452"), HandType::Straight);
+    }
+
+    #[test]
+    fn test_compare_explained() {
+        let explain = |a: &str, b: &str, rules: &RuleSet| {
+            let input = parse_hands(&format!("{a} 1\n{b} 2"), rules).unwrap();
+            compare_explained(&input.hands[0].0, &input.hands[1].0, rules)
+        };
+
+        let comparison = explain("QQQJA", "KTJJT", &RuleSet::standard());
+        assert_eq!(comparison.ordering, Ordering::Greater);
+        assert_eq!(comparison.reason, Reason::Type(HandType::ThreeOfAKind, HandType::TwoPair));
+        assert_eq!(comparison.to_string(), "ThreeOfAKind vs TwoPair");
+
+        let comparison = explain("KTJJT", "QQQJA", &RuleSet::jokers());
+        assert_eq!(comparison.ordering, Ordering::Greater);
+        assert_eq!(comparison.to_string(), "tie on type, decided at position 1: K > Q");
+
+        let comparison = explain("KQ234", "KJ234", &RuleSet::standard());
+        assert_eq!(comparison.reason, Reason::Card { position: 2, a: Q, b: J });
+        assert_eq!(comparison.to_string(), "tie on type, decided at position 2: Q > J");
+        assert_eq!(explain("JKKQ2", "QKKJ2", &RuleSet::jokers()).to_string(), "tie on type, decided at position 1: J < Q");
+
+        let comparison = explain("KQ33J", "KQ333", &RuleSet::jokers_not_in_ties());
+        assert_eq!(comparison.ordering, Ordering::Less);
+        assert_eq!(comparison.reason, Reason::Length(4, 5));
+
+        let comparison = explain("KQ234", "KQ234", &RuleSet::standard());
+        assert_eq!(comparison.ordering, Ordering::Equal);
+        assert_eq!(comparison.reason, Reason::Tie);
+    }
+
+    #[test]
+    fn test_ranking() {
+        let input = parse_input(EXAMPLE_INPUT).unwrap();
+        let ranked = ranking(&input, &RuleSet::jokers());
+
+        let summary = ranked.iter().map(|r| (r.rank, r.hand.to_string(), r.hand_type, r.winnings)).collect::<Vec<_>>();
+        assert_eq!(summary, [
+            (1, "32T3K".to_owned(), HandType::OnePair, 765),
+            (2, "KK677".to_owned(), HandType::TwoPair, 56),
+            (3, "T55J5".to_owned(), HandType::FourOfAKind, 2052),
+            (4, "QQQJA".to_owned(), HandType::FourOfAKind, 1932),
+            (5, "KTJJT".to_owned(), HandType::FourOfAKind, 1100),
+        ]);
+        assert_eq!(ranked[0].substitution, []);
+        assert_eq!(ranked[3].substitution, [(J, Q)]);
+        assert_eq!(ranked[4].substitution, [(J, T), (J, T)]);
+
+        let input = parse_hands("T9Q82 1\n2s3s2s5s6s 2", &RuleSet::poker()).unwrap();
+        let rules = RuleSet { wild: vec![C2], ..RuleSet::poker() };
+        let ranked = ranking(&input, &rules);
+        assert_eq!(ranked[0].hand.to_string(), "T9Q82");
+        assert_eq!(ranked[0].substitution, [(C2, J)]);
+        assert_eq!(ranked[1].hand_type, HandType::StraightFlush);
+        assert_eq!(ranked[1].substitution, [(C2, C4), (C2, C7)]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = EXAMPLE_INPUT.replace("KK677", "KK6X7");
+        assert_eq!(parse_input(&input).err().unwrap().to_string(), "line 3, col 4: unknown card 'X'");
+
+        let input = EXAMPLE_INPUT.replace("KTJJT", "KTJJ");
+        assert_eq!(parse_input(&input).err(), Some(Error::new("expected 5 cards, found 4").with_line(4).with_column(1)));
+
+        assert_eq!(parse_input("32T3K").err(), Some(Error::new("expected a hand and a bid").with_line(1)));
+
+        let rules = RuleSet::standard().with_hand_size(3);
+        assert_eq!(parse_hands(EXAMPLE_INPUT, &rules).err().unwrap().to_string(), "line 1, col 1: expected 3 cards, found 5");
+        assert_eq!(parse_input("2h3h4h5h6 1").err().unwrap().to_string(), "line 1, col 1: expected a suit on every card or on none");
+        assert_eq!(parse_input("2h3h4x5h6h 1").err().unwrap().to_string(), "line 1, col 6: unknown card 'x'");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use itertools::{FoldWhile, Itertools};
+use num::Integer;
+use Direction::{Left, Right};
+use crate::{column_of, Error, Result, Solution, split_once};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input = Context;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Context> { input.parse() }
+
+    fn part1(input: &Context) -> usize { part1(input) }
+
+    fn part2(input: &Context) -> usize { part2(input) }
+}
+
+fn part1(context: &Context) -> usize {
+    solve_one(context, ['A', 'A', 'A'], |c| c == ['Z', 'Z', 'Z'])
+}
+
+fn solve_one<F: Fn([char; 3]) -> bool>(context: &Context, start: Name, end_condition: F) -> usize {
+    context.directions.iter()
+        .cycle()
+        .fold_while((start, 0), |(current, i), direction| {
+            let (left, right) = &context.junctions[&current];
+
+            let next = match direction {
+                Left => *left,
+                Right => *right,
+            };
+
+            if end_condition(next) {
+                FoldWhile::Done((next, i + 1))
+            } else {
+                FoldWhile::Continue((next, i + 1))
+            }
+        }).into_inner().1
+}
+
+fn part2(context: &Context) -> usize {
+    context.junctions.keys()
+        .filter(|k| k[2] == 'A')
+        .cloned()
+        .map(|start| solve_one(context, start, |c| c[2] == 'Z'))
+        .reduce(|acc, e| acc.lcm(&e)).unwrap()
+}
+
+pub struct Context {
+    directions: Directions,
+    junctions: Junctions,
+}
+
+impl FromStr for Context {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let directions = lines.next()
+            .ok_or_else(|| Error::new("empty input"))?
+            .chars()
+            .enumerate()
+            .map(|(i, c)| Direction::try_from(c).map_err(|e| e.with_line(1).with_column(i + 1)))
+            .collect::<Result<Directions>>()?;
+
+        let junctions = lines.enumerate()
+            .skip(1)
+            .map(|(i, l)| parse_junction(l).map_err(|e| e.with_line(i + 2)))
+            .collect::<Result<Junctions>>()?;
+
+        Ok(Context { directions, junctions })
+    }
+}
+
+fn parse_junction(line: &str) -> Result<(Name, (Name, Name))> {
+    let (name, targets) = split_once(line, " = ")?;
+    let targets = targets.strip_prefix('(').and_then(|t| t.strip_suffix(')'))
+        .ok_or_else(|| Error::at(line, targets, "expected '(<left>, <right>)'"))?;
+    let (left, right) = split_once(targets, ", ").map_err(|e| e.with_column(column_of(line, targets)))?;
+
+    Ok((parse_name(line, name)?, (parse_name(line, left)?, parse_name(line, right)?)))
+}
+
+fn parse_name(line: &str, name: &str) -> Result<Name> {
+    name.chars().collect_vec().try_into()
+        .map_err(|_| Error::at(line, name, format!("invalid name '{name}'")))
+}
+
+#[derive(Debug)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Left),
+            'R' => Ok(Right),
+            _ => Err(Error::new(format!("unknown direction '{value}'"))),
+        }
+    }
+}
+
+type Directions = Vec<Direction>;
+type Name = [char; 3];
+type Junctions = HashMap<Name, (Name, Name)>;
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+    use super::{Context, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        let input1 = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(part1(&input1.parse().unwrap()), 2);
+
+        let input2 = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(part1(&input2.parse().unwrap()), 6);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        assert_eq!(part2(&input.parse().unwrap()), 6);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("RXL\n\nAAA = (BBB, CCC)".parse::<Context>().err(), Some(Error::new("unknown direction 'X'").with_line(1).with_column(2)));
+        assert_eq!("RL\n\nAAA = (BBB, CCC)\nBB = (AAA, AAA)".parse::<Context>().err(), Some(Error::new("invalid name 'BB'").with_line(4).with_column(1)));
+        assert_eq!("RL\n\nAAA = BBB, CCC".parse::<Context>().err(), Some(Error::new("expected '(<left>, <right>)'").with_line(3).with_column(7)));
+    }
+}
\ No newline at end of file
@@ -1,12 +1,29 @@
-use itertools::Itertools;
+use crate::{parse_field, Result, Solution};
 
-fn main() {
-    aoc2023::run_day(|| prepare_input(&aoc2023::read_input_as_string()), part1, part2);
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Input = Vec<Vec<Vec<i32>>>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Vec<i32>>>> { prepare_input(input) }
+
+    fn part1(input: &Vec<Vec<Vec<i32>>>) -> i32 { part1(input) }
+
+    fn part2(input: &Vec<Vec<Vec<i32>>>) -> i32 { part2(input) }
 }
 
-fn prepare_input(input: &str) -> Vec<Vec<Vec<i32>>> {
+fn prepare_input(input: &str) -> Result<Vec<Vec<Vec<i32>>>> {
     input.lines()
-        .map(|l| stabilize(l.split_whitespace().map(|n| n.parse().unwrap()).collect_vec()))
+        .enumerate()
+        .map(|(i, l)| l.split_whitespace()
+            .map(|n| parse_field(l, n).map_err(|e| e.with_line(i + 1)))
+            .collect::<Result<Vec<_>>>()
+            .map(stabilize))
         .collect()
 }
 
@@ -40,7 +57,7 @@ fn stabilize(start: Vec<i32>) -> Vec<Vec<i32>> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{prepare_input, part1, part2};
+    use super::{prepare_input, part1, part2};
 
     const EXAMPLE_INPUT: &str = "0 3 6 9 12 15
 1 3 6 10 15 21
@@ -48,11 +65,11 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&prepare_input(EXAMPLE_INPUT)), 114);
+        assert_eq!(part1(&prepare_input(EXAMPLE_INPUT).unwrap()), 114);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&prepare_input(EXAMPLE_INPUT)), 2);
+        assert_eq!(part2(&prepare_input(EXAMPLE_INPUT).unwrap()), 2);
     }
 }
\ No newline at end of file
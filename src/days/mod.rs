@@ -0,0 +1,42 @@
+use crate::DynSolution;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+
+/// Every implemented day, ordered by day number.
+pub const SOLUTIONS: [&dyn DynSolution; 10] = [
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS.iter().find(|s| s.day() == day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, SOLUTIONS};
+
+    #[test]
+    fn test_registry() {
+        assert!(SOLUTIONS.windows(2).all(|w| w[0].day() < w[1].day()));
+        assert_eq!(find(5).map(|s| s.title()), Some("If You Give A Seed A Fertilizer"));
+        assert!(find(25).is_none());
+    }
+}
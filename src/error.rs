@@ -0,0 +1,144 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// An error raised while reading or parsing puzzle input.
+///
+/// Lines and columns are 1-based. Parsers of a single line leave the line unset and let the
+/// caller fill it in, while the harness attaches the day the error occurred in.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Error {
+    day: Option<u8>,
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Error { day: None, line: None, column: None, message: message.into() }
+    }
+
+    /// Creates an error pointing at `part`, which must be a subslice of `line`.
+    pub fn at(line: &str, part: &str, message: impl Into<String>) -> Self {
+        Error::new(message).with_column(column_of(line, part))
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Moves the line by `by` lines, for errors raised while parsing part of a larger input.
+    pub fn shift_line(mut self, by: usize) -> Self {
+        self.line = self.line.map(|l| l + by);
+        self
+    }
+
+    /// Moves the column by `by` columns, for errors raised while parsing part of a line.
+    pub fn shift_column(mut self, by: usize) -> Self {
+        self.column = self.column.map(|c| c + by);
+        self
+    }
+
+    pub fn day(&self) -> Option<u8> { self.day }
+
+    pub fn line(&self) -> Option<usize> { self.line }
+
+    pub fn column(&self) -> Option<usize> { self.column }
+
+    pub fn message(&self) -> &str { &self.message }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day{day}: ")?;
+        }
+
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, col {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(column)) => write!(f, "col {column}: ")?,
+            (None, None) => {}
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::new(value.to_string())
+    }
+}
+
+/// The 1-based column at which `part`, a subslice of `line`, starts.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+
+    match line.get(..offset) {
+        Some(prefix) if offset + part.len() <= line.len() => prefix.chars().count() + 1,
+        _ => 1,
+    }
+}
+
+/// Parses `part`, a subslice of `line`, reporting its column on failure.
+pub fn parse_field<T: FromStr>(line: &str, part: &str) -> Result<T> {
+    part.parse().map_err(|_| Error::at(line, part, format!("invalid number '{part}'")))
+}
+
+/// Splits `s` at the first occurrence of `delimiter`, failing with a message naming the delimiter.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+    s.split_once(delimiter).ok_or_else(|| Error::new(format!("expected '{delimiter}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{column_of, Error, parse_field};
+
+    #[test]
+    fn test_display() {
+        let error = Error::new("unknown card 'X'").with_column(6).with_line(3).with_day(7);
+        assert_eq!(error.to_string(), "day7: line 3, col 6: unknown card 'X'");
+        assert_eq!(Error::new("empty input").with_day(1).to_string(), "day1: empty input");
+        assert_eq!(Error::new("bad").with_line(2).to_string(), "line 2: bad");
+    }
+
+    #[test]
+    fn test_shift() {
+        let error = Error::new("bad").with_line(1).with_column(2).shift_line(4).shift_column(3);
+        assert_eq!((error.line(), error.column()), (Some(5), Some(5)));
+
+        let error = Error::new("bad").shift_line(4).shift_column(3);
+        assert_eq!((error.line(), error.column()), (None, None));
+    }
+
+    #[test]
+    fn test_column_of() {
+        let line = "Game 12: 3 blue";
+        assert_eq!(column_of(line, &line[..4]), 1);
+        assert_eq!(column_of(line, &line[5..7]), 6);
+        assert_eq!(column_of(line, &"x".repeat(100)), 1);
+    }
+
+    #[test]
+    fn test_parse_field() {
+        let line = "Game x: 3 blue";
+        assert_eq!(parse_field::<usize>(line, &line[8..9]), Ok(3));
+        assert_eq!(parse_field::<usize>(line, &line[5..6]), Err(Error::new("invalid number 'x'").with_column(6)));
+    }
+}
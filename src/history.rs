@@ -0,0 +1,309 @@
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use crate::{Error, parse_field, Result};
+use crate::output::Record;
+
+/// Default location of the timing history.
+pub const HISTORY_FILE: &str = "history.csv";
+
+const HEADER: &str = "timestamp_ms,day,phase,duration_ns,baseline,mode,input";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Phase {
+    Prepare,
+    Part1,
+    Part2,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Prepare => write!(f, "prepare"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prepare" => Ok(Phase::Prepare),
+            "part1" => Ok(Phase::Part1),
+            "part2" => Ok(Phase::Part2),
+            _ => Err(Error::new(format!("unknown phase '{s}'"))),
+        }
+    }
+}
+
+/// How a duration was measured. Only durations measured the same way are compared.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Mode {
+    /// A single run.
+    Single,
+    /// The median of a benchmark.
+    Bench,
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mode::Single => write!(f, "single"),
+            Mode::Bench => write!(f, "bench"),
+        }
+    }
+}
+
+impl FromStr for Mode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "single" => Ok(Mode::Single),
+            "bench" => Ok(Mode::Bench),
+            _ => Err(Error::new(format!("unknown mode '{s}'"))),
+        }
+    }
+}
+
+/// The duration of one phase of one day and input in a recorded run.
+///
+/// All entries of a run share its timestamp, and baseline runs are marked as such.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Entry {
+    pub timestamp: u128,
+    pub day: u8,
+    pub input: String,
+    pub phase: Phase,
+    pub duration: Duration,
+    pub baseline: bool,
+    pub mode: Mode,
+}
+
+impl Entry {
+    /// Splits records into an entry per phase, counting the shared preparation once.
+    pub fn from_records(records: &[Record], timestamp: u128, baseline: bool) -> Vec<Entry> {
+        let entry = |record: &Record, phase, duration| Entry {
+            timestamp,
+            day: record.day,
+            input: record.input.clone(),
+            phase,
+            duration,
+            baseline,
+            mode: if record.stats.is_some() { Mode::Bench } else { Mode::Single },
+        };
+
+        records.iter()
+            .flat_map(|r| match r.part {
+                1 => vec![entry(r, Phase::Prepare, r.prepare), entry(r, Phase::Part1, r.duration)],
+                _ => vec![entry(r, Phase::Part2, r.duration)],
+            })
+            .collect()
+    }
+
+    fn is_same_phase(&self, other: &Entry) -> bool {
+        self.day == other.day && self.input == other.input && self.phase == other.phase && self.mode == other.mode
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{},{},{},{},{}", self.timestamp, self.day, self.phase, self.duration.as_nanos(),
+               self.baseline, self.mode, self.input)
+    }
+}
+
+impl FromStr for Entry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [timestamp, day, phase, duration, baseline, mode, input] = s.splitn(7, ',').collect::<Vec<_>>()[..] else {
+            return Err(Error::new(format!("expected 7 fields: {HEADER}")));
+        };
+
+        Ok(Entry {
+            timestamp: parse_field(s, timestamp)?,
+            day: parse_field(s, day)?,
+            input: input.to_owned(),
+            phase: phase.parse().map_err(|e: Error| e.with_column(crate::column_of(s, phase)))?,
+            duration: Duration::from_nanos(parse_field(s, duration)?),
+            baseline: baseline.parse()
+                .map_err(|_| Error::at(s, baseline, format!("expected true or false, found '{baseline}'")))?,
+            mode: mode.parse().map_err(|e: Error| e.with_column(crate::column_of(s, mode)))?,
+        })
+    }
+}
+
+/// Every run recorded in a history file, oldest first.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+impl History {
+    /// Loads the history at `path`, which is empty if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<History> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => content.parse().map_err(|e: Error| Error::new(format!("{}: {e}", path.display()))),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(Error::new(format!("could not read {}: {e}", path.display()))),
+        }
+    }
+
+    /// Appends `entries` to the history file at `path`, creating it if necessary.
+    pub fn append(path: &Path, entries: &[Entry]) -> Result<()> {
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)
+            .map_err(|e| Error::new(format!("could not open {}: {e}", path.display())))?;
+
+        if is_new {
+            writeln!(file, "{HEADER}")?;
+        }
+        for entry in entries {
+            writeln!(file, "{entry}")?;
+        }
+
+        Ok(())
+    }
+
+    /// The most recent entry of the same phase, day, input and mode as `entry`.
+    pub fn previous(&self, entry: &Entry) -> Option<&Entry> {
+        self.entries.iter().rev().find(|e| e.is_same_phase(entry))
+    }
+
+    /// The most recent baseline entry of the same phase, day, input and mode as `entry`.
+    pub fn baseline(&self, entry: &Entry) -> Option<&Entry> {
+        self.entries.iter().rev().find(|e| e.baseline && e.is_same_phase(entry))
+    }
+}
+
+impl FromStr for History {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s.lines()
+            .enumerate()
+            .filter(|(_, l)| !l.is_empty() && *l != HEADER)
+            .map(|(i, l)| l.parse().map_err(|e: Error| e.with_line(i + 1)))
+            .collect::<Result<_>>()?;
+
+        Ok(History { entries })
+    }
+}
+
+/// A phase that got slower than allowed compared with an earlier run.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub input: String,
+    pub phase: Phase,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Regression {
+    /// The relative slowdown in percent.
+    pub fn change(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {} ({}) {}: {:?} -> {:?} (+{:.1}%)",
+               self.day, self.input, self.phase, self.before, self.after, self.change())
+    }
+}
+
+/// Finds the phases of `current` that are more than `threshold` percent slower than the entry
+/// `reference` picks for them. Phases without a reference are skipped.
+pub fn find_regressions<'a>(current: &[Entry], reference: impl Fn(&Entry) -> Option<&'a Entry>, threshold: f64) -> Vec<Regression> {
+    current.iter()
+        .filter_map(|entry| {
+            let before = reference(entry)?.duration;
+            let regression = Regression {
+                day: entry.day,
+                input: entry.input.clone(),
+                phase: entry.phase,
+                before,
+                after: entry.duration,
+            };
+
+            (entry.duration > before && regression.change() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::Error;
+    use crate::output::Record;
+    use super::{Entry, find_regressions, History, Mode, Phase};
+
+    fn record(part: u8, prepare: u64, duration: u64) -> Record {
+        Record {
+            day: 5,
+            input: "day05".to_owned(),
+            part,
+            answer: "35".to_owned(),
+            prepare: Duration::from_micros(prepare),
+            duration: Duration::from_micros(duration),
+            stats: None,
+        }
+    }
+
+    #[test]
+    fn test_entries_from_records() {
+        let entries = Entry::from_records(&[record(1, 10, 20), record(2, 10, 30)], 7, true);
+
+        assert_eq!(entries.iter().map(|e| (e.phase, e.duration.as_micros())).collect::<Vec<_>>(),
+                   [(Phase::Prepare, 10), (Phase::Part1, 20), (Phase::Part2, 30)]);
+        assert!(entries.iter().all(|e| e.timestamp == 7 && e.baseline));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let entries = Entry::from_records(&[record(1, 10, 20), record(2, 10, 30)], 7, false);
+        let content = entries.iter().map(Entry::to_string).collect::<Vec<_>>().join("\n");
+
+        assert_eq!(content.lines().next(), Some("7,5,prepare,10000,false,single,day05"));
+        assert_eq!(content.parse::<History>(), Ok(History { entries }));
+        assert_eq!("7,5,warmup,10,false,single,x".parse::<History>(), Err(Error::new("unknown phase 'warmup'").with_line(1).with_column(5)));
+        assert_eq!("7,5,part1,10,false,best,x".parse::<History>(), Err(Error::new("unknown mode 'best'").with_line(1).with_column(20)));
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let mut history = History::default();
+        history.entries.extend(Entry::from_records(&[record(1, 10, 100), record(2, 10, 100)], 1, true));
+        history.entries.extend(Entry::from_records(&[record(1, 10, 200), record(2, 10, 50)], 2, false));
+
+        let current = Entry::from_records(&[record(1, 10, 115), record(2, 30, 105)], 3, false);
+
+        let regressions = find_regressions(&current, |e| history.previous(e), 10.0);
+        assert_eq!(regressions.iter().map(|r| r.phase).collect::<Vec<_>>(), [Phase::Part2]);
+        assert_eq!(regressions[0].to_string(), "day 5 (day05) part2: 50µs -> 105µs (+110.0%)");
+
+        let regressions = find_regressions(&current, |e| history.baseline(e), 10.0);
+        assert_eq!(regressions.iter().map(|r| r.phase).collect::<Vec<_>>(), [Phase::Part1]);
+    }
+
+    #[test]
+    fn test_modes_are_not_compared() {
+        let mut history = History::default();
+        let mut benched = Entry::from_records(&[record(1, 10, 100), record(2, 10, 100)], 1, true);
+        benched.iter_mut().for_each(|e| e.mode = Mode::Bench);
+        history.entries.extend(benched);
+
+        let current = Entry::from_records(&[record(1, 50, 500), record(2, 50, 500)], 2, false);
+        assert!(current.iter().all(|e| history.previous(e).is_none() && history.baseline(e).is_none()));
+        assert_eq!(find_regressions(&current, |e| history.previous(e), 10.0), []);
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use crate::{Error, Result};
+
+/// Directory holding the conventional `dayNN.txt` inputs.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Where the input of a day is read from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Resolves an input argument: `-` is stdin, any other value a path and no value the
+    /// conventional `inputs/dayNN.txt` of `day`.
+    pub fn resolve(day: u8, argument: Option<&str>) -> InputSource {
+        match argument {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::File(default_path(day)),
+        }
+    }
+
+    /// Expands an input argument into every input it refers to: all files of a directory, the
+    /// files matching a glob (`*` and `?` in the file name only) or the single resolved input.
+    pub fn expand(day: u8, argument: Option<&str>) -> Result<Vec<InputSource>> {
+        let source = InputSource::resolve(day, argument);
+        let InputSource::File(path) = &source else {
+            return Ok(vec![source]);
+        };
+
+        let pattern = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        let (directory, pattern) = if path.is_dir() {
+            (path.as_path(), "*")
+        } else if pattern.contains(['*', '?']) {
+            (path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new(".")), pattern.as_str())
+        } else {
+            return Ok(vec![source]);
+        };
+
+        let entries = std::fs::read_dir(directory)
+            .map_err(|e| Error::new(format!("could not read directory {}: {e}", directory.display())))?;
+
+        let mut paths = vec![];
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_file() && matches_glob(pattern, &entry.file_name().to_string_lossy()) {
+                paths.push(entry.path());
+            }
+        }
+
+        if paths.is_empty() {
+            return Err(Error::new(format!("no inputs found matching {}", path.display())));
+        }
+
+        paths.sort();
+        Ok(paths.into_iter().map(InputSource::File).collect())
+    }
+
+    /// The name answers and reports refer to this input by: the file name without extension.
+    pub fn name(&self) -> String {
+        match self {
+            InputSource::Stdin => "stdin".to_owned(),
+            InputSource::File(path) => path.file_stem()
+                .map_or_else(|| path.display().to_string(), |s| s.to_string_lossy().into_owned()),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::Stdin => crate::read_input_as_string(),
+            InputSource::File(path) => read_file(path),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(INPUT_DIR).join(format!("day{day:02}.txt"))
+}
+
+/// Matches `name` against `pattern`, where `*` matches any sequence and `?` any single character.
+fn matches_glob(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+
+    // matches[j] holds whether the pattern consumed so far matches the first j characters of name.
+    let mut matches = vec![false; name.len() + 1];
+    matches[0] = true;
+
+    for p in pattern {
+        let previous = std::mem::replace(&mut matches, vec![false; name.len() + 1]);
+        for j in 0..=name.len() {
+            matches[j] = match p {
+                '*' => previous[j] || (j > 0 && matches[j - 1]),
+                '?' => j > 0 && previous[j - 1],
+                c => j > 0 && previous[j - 1] && name[j - 1] == c,
+            };
+        }
+    }
+
+    matches[name.len()]
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => Error::new(format!(
+            "input file {} not found, pass a path or - to read from stdin", path.display())),
+        _ => Error::new(format!("could not read {}: {e}", path.display())),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use crate::Error;
+    use super::{InputSource, matches_glob};
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(InputSource::resolve(5, None), InputSource::File(PathBuf::from("inputs/day05.txt")));
+        assert_eq!(InputSource::resolve(12, Some("-")), InputSource::Stdin);
+        assert_eq!(InputSource::resolve(5, Some("example.txt")), InputSource::File(PathBuf::from("example.txt")));
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(InputSource::resolve(5, None).name(), "day05");
+        assert_eq!(InputSource::resolve(5, Some("-")).name(), "stdin");
+        assert_eq!(InputSource::resolve(5, Some("team/alice.txt")).name(), "alice");
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = InputSource::resolve(5, Some("does/not/exist.txt"));
+        assert_eq!(source.read(), Err(Error::new("input file does/not/exist.txt not found, pass a path or - to read from stdin")));
+    }
+
+    #[test]
+    fn test_matches_glob() {
+        assert!(matches_glob("*", "alice.txt"));
+        assert!(matches_glob("*.txt", "alice.txt"));
+        assert!(matches_glob("day0?.txt", "day05.txt"));
+        assert!(matches_glob("a*e*.txt", "alice.txt"));
+        assert!(!matches_glob("*.txt", "alice.md"));
+        assert!(!matches_glob("day0?.txt", "day5.txt"));
+        assert!(!matches_glob("alice", "alice.txt"));
+    }
+
+    #[test]
+    fn test_expand() {
+        let directory = std::env::temp_dir().join(format!("aoc2023-expand-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        for name in ["bob.txt", "alice.txt", "notes.md"] {
+            std::fs::write(directory.join(name), "").unwrap();
+        }
+
+        let names = |argument: &str| InputSource::expand(5, Some(argument))
+            .map(|sources| sources.iter().map(InputSource::name).collect::<Vec<_>>());
+
+        assert_eq!(names(directory.to_str().unwrap()), Ok(vec!["alice".to_owned(), "bob".to_owned(), "notes".to_owned()]));
+        assert_eq!(names(directory.join("*.txt").to_str().unwrap()), Ok(vec!["alice".to_owned(), "bob".to_owned()]));
+        assert!(names(directory.join("*.csv").to_str().unwrap()).is_err());
+        assert_eq!(names("-"), Ok(vec!["stdin".to_owned()]));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
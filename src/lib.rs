@@ -1,26 +1,87 @@
-use std::borrow::Borrow;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
 use std::io::Read;
 use std::str::FromStr;
 use std::time::{Duration, Instant};
 
-pub fn run_day<P, F1, F2, I, I1, I2, U, V>(prepare: P, part1: F1, part2: F2)
-    where P: FnOnce() -> I,
-          I: Borrow<I1> + Borrow<I2>,
-          F1: FnOnce(&I1) -> U,
-          F2: FnOnce(&I2) -> V,
-          I1: ?Sized, I2: ?Sized,
-          U: Debug, V: Debug {
-    let (input, prepare_duration) = time_function(prepare);
-    let (solution1, part1_duration) = time_function(|| part1(input.borrow()));
-    let (solution2, part2_duration) = time_function(|| part2(input.borrow()));
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod history;
+pub mod input;
+pub mod output;
+mod error;
 
-    eprintln!("Input preparation runtime: {prepare_duration:?}");
-    eprintln!("Part 1: {solution1:?} ({part1_duration:?})");
-    eprintln!("Part 2: {solution2:?} ({part2_duration:?})");
+pub use error::{column_of, Error, parse_field, Result, split_once};
 
-    let total_duration = prepare_duration + part1_duration + part2_duration;
-    eprintln!("Total runtime: {total_duration:?}");
+/// A puzzle solution: how to prepare the input and how to solve both parts from it.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type Output1: Debug;
+    type Output2: Debug;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+}
+
+/// Object-safe view of a [`Solution`], allowing solutions to be stored in a registry.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn run(&self, input: &str) -> Result<Run>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 { S::DAY }
+
+    fn title(&self) -> &'static str { S::TITLE }
+
+    fn run(&self, input: &str) -> Result<Run> { run_day::<S>(input) }
+}
+
+/// The answers of a single run of a day together with the runtime of each phase.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub day: u8,
+    pub part1: String,
+    pub part2: String,
+    pub prepare_duration: Duration,
+    pub part1_duration: Duration,
+    pub part2_duration: Duration,
+}
+
+impl Run {
+    pub fn total_duration(&self) -> Duration {
+        self.prepare_duration + self.part1_duration + self.part2_duration
+    }
+}
+
+impl Display for Run {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Input preparation runtime: {:?}", self.prepare_duration)?;
+        writeln!(f, "Part 1: {} ({:?})", self.part1, self.part1_duration)?;
+        writeln!(f, "Part 2: {} ({:?})", self.part2, self.part2_duration)?;
+        write!(f, "Total runtime: {:?}", self.total_duration())
+    }
+}
+
+pub fn run_day<S: Solution>(input: &str) -> Result<Run> {
+    let (input, prepare_duration) = time_function(|| S::parse(input));
+    let input = input.map_err(|e| e.with_day(S::DAY))?;
+    let (solution1, part1_duration) = time_function(|| S::part1(&input));
+    let (solution2, part2_duration) = time_function(|| S::part2(&input));
+
+    Ok(Run {
+        day: S::DAY,
+        part1: format!("{solution1:?}"),
+        part2: format!("{solution2:?}"),
+        prepare_duration,
+        part1_duration,
+        part2_duration,
+    })
 }
 
 pub fn time_function<F: FnOnce() -> T, T>(f: F) -> (T, Duration) {
@@ -30,22 +91,24 @@ pub fn time_function<F: FnOnce() -> T, T>(f: F) -> (T, Duration) {
     (result, elapsed)
 }
 
-pub fn read_input_as_string() -> String {
+pub fn read_input_as_string() -> Result<String> {
     let mut input = String::new();
-    std::io::stdin().read_to_string(&mut input).unwrap();
-    input
+    std::io::stdin().read_to_string(&mut input)?;
+    Ok(input)
 }
 
-pub fn parse_lines_from_stdin<T: FromStr>() -> Vec<T> where T::Err: Debug {
+pub fn parse_lines_from_stdin<T: FromStr<Err = Error>>() -> Result<Vec<T>> {
     std::io::stdin()
         .lines()
-        .map(|l| l.unwrap().parse().unwrap())
+        .enumerate()
+        .map(|(i, l)| l?.parse().map_err(|e: Error| e.with_line(i + 1)))
         .collect()
 }
 
-pub fn parse_lines_from_str<T: FromStr>(input: &'static str) -> Vec<T> where T::Err: Debug {
+pub fn parse_lines_from_str<T: FromStr<Err = Error>>(input: &str) -> Result<Vec<T>> {
     input
         .lines()
-        .map(|l| l.parse().unwrap())
+        .enumerate()
+        .map(|(i, l)| l.parse().map_err(|e: Error| e.with_line(i + 1)))
         .collect()
-}
\ No newline at end of file
+}
@@ -0,0 +1,215 @@
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+use itertools::Itertools;
+use crate::{Error, Run};
+use crate::bench::{Bench, Stats};
+
+/// How the harness reports answers and timings.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum Format {
+    /// Human readable text on stderr.
+    #[default]
+    Text,
+    /// One JSON object per line on stdout.
+    Json,
+    /// Comma separated values with a header on stdout.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(Error::new(format!("unknown format '{s}', expected text, json or csv"))),
+        }
+    }
+}
+
+/// The answer and timings of a single part, flattened for machine-readable output.
+///
+/// In bench mode the durations are medians and `stats` holds the full statistics of the part.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub input: String,
+    pub part: u8,
+    pub answer: String,
+    pub prepare: Duration,
+    pub duration: Duration,
+    pub stats: Option<Stats>,
+}
+
+impl Record {
+    pub fn from_run(run: &Run, input: &str) -> [Record; 2] {
+        let record = |part, answer: &String, duration| Record {
+            day: run.day,
+            input: input.to_owned(),
+            part,
+            answer: answer.clone(),
+            prepare: run.prepare_duration,
+            duration,
+            stats: None,
+        };
+
+        [record(1, &run.part1, run.part1_duration), record(2, &run.part2, run.part2_duration)]
+    }
+
+    pub fn from_bench(bench: &Bench, input: &str) -> [Record; 2] {
+        let record = |part, answer: &String, stats: Stats| Record {
+            day: bench.day,
+            input: input.to_owned(),
+            part,
+            answer: answer.clone(),
+            prepare: bench.prepare_stats.median,
+            duration: stats.median,
+            stats: Some(stats),
+        };
+
+        [record(1, &bench.part1, bench.part1_stats), record(2, &bench.part2, bench.part2_stats)]
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = format!(r#"{{"day":{},"input":{},"part":{},"answer":{},"prepare_ns":{},"duration_ns":{}"#,
+                               self.day, json_string(&self.input), self.part, json_string(&self.answer),
+                               self.prepare.as_nanos(), self.duration.as_nanos());
+
+        if let Some(stats) = &self.stats {
+            write!(json, r#","samples":{},"min_ns":{},"median_ns":{},"mean_ns":{},"p95_ns":{},"stddev_ns":{}"#,
+                   stats.samples, stats.min.as_nanos(), stats.median.as_nanos(), stats.mean.as_nanos(),
+                   stats.p95.as_nanos(), stats.stddev.as_nanos()).unwrap();
+        }
+
+        json.push('}');
+        json
+    }
+
+    pub fn to_csv(&self) -> String {
+        let stats = self.stats.map_or(",,,,,".to_owned(), |s| format!(
+            "{},{},{},{},{},{}", s.samples, s.min.as_nanos(), s.median.as_nanos(), s.mean.as_nanos(),
+            s.p95.as_nanos(), s.stddev.as_nanos()));
+
+        format!("{},{},{},{},{},{},{}", self.day, csv_field(&self.input), self.part, csv_field(&self.answer),
+                self.prepare.as_nanos(), self.duration.as_nanos(), stats)
+    }
+}
+
+pub const CSV_HEADER: &str = "day,input,part,answer,prepare_ns,duration_ns,samples,min_ns,median_ns,mean_ns,p95_ns,stddev_ns";
+
+/// Renders the records of one or more inputs as a table with a row per day and input.
+pub fn table(records: &[Record]) -> String {
+    let mut rows = vec![["Day", "Input", "Part 1", "Part 2", "Prepare", "Part 1 time", "Part 2 time", "Total"]
+        .map(String::from)];
+
+    for (part1, part2) in records.iter().tuples() {
+        rows.push([
+            part1.day.to_string(),
+            part1.input.clone(),
+            part1.answer.clone(),
+            part2.answer.clone(),
+            format!("{:?}", part1.prepare),
+            format!("{:?}", part1.duration),
+            format!("{:?}", part2.duration),
+            format!("{:?}", part1.prepare + part1.duration + part2.duration),
+        ]);
+    }
+
+    let widths = (0..rows[0].len())
+        .map(|c| rows.iter().map(|r| r[c].chars().count()).max().unwrap_or(0))
+        .collect_vec();
+
+    rows.iter()
+        .map(|r| r.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).join("  ").trim_end().to_owned())
+        .join("\n")
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::bench::Stats;
+    use crate::Run;
+    use super::{csv_field, json_string, Record, table};
+
+    fn run() -> Run {
+        Run {
+            day: 6,
+            part1: "288".to_owned(),
+            part2: "71503".to_owned(),
+            prepare_duration: Duration::from_nanos(100),
+            part1_duration: Duration::from_nanos(2000),
+            part2_duration: Duration::from_nanos(3000),
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let [part1, part2] = Record::from_run(&run(), "day06");
+        assert_eq!(part1.to_json(), r#"{"day":6,"input":"day06","part":1,"answer":"288","prepare_ns":100,"duration_ns":2000}"#);
+        assert_eq!(part2.to_json(), r#"{"day":6,"input":"day06","part":2,"answer":"71503","prepare_ns":100,"duration_ns":3000}"#);
+    }
+
+    #[test]
+    fn test_json_with_stats() {
+        let d = Duration::from_nanos;
+        let stats = Stats { samples: 3, min: d(1), median: d(2), mean: d(3), p95: d(4), stddev: d(5) };
+        let record = Record { stats: Some(stats), ..Record::from_run(&run(), "day06")[0].clone() };
+
+        assert_eq!(record.to_json(), r#"{"day":6,"input":"day06","part":1,"answer":"288","prepare_ns":100,"duration_ns":2000,"samples":3,"min_ns":1,"median_ns":2,"mean_ns":3,"p95_ns":4,"stddev_ns":5}"#);
+        assert_eq!(record.to_csv(), "6,day06,1,288,100,2000,3,1,2,3,4,5");
+    }
+
+    #[test]
+    fn test_csv() {
+        let [part1, _] = Record::from_run(&run(), "day06");
+        assert_eq!(part1.to_csv(), "6,day06,1,288,100,2000,,,,,,");
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("(1, \"x\")"), r#""(1, ""x"")""#);
+    }
+
+    #[test]
+    fn test_table() {
+        let mut records = Record::from_run(&run(), "alice").to_vec();
+        records.extend(Record::from_run(&Run { part1: "1".to_owned(), ..run() }, "bob"));
+
+        assert_eq!(table(&records), "\
+Day  Input  Part 1  Part 2  Prepare  Part 1 time  Part 2 time  Total
+6    alice  288     71503   100ns    2µs          3µs          5.1µs
+6    bob    1       71503   100ns    2µs          3µs          5.1µs");
+    }
+}